@@ -25,15 +25,12 @@ fn main() -> anyhow::Result<()> {
 
     // Visualize
     println!("{}", h.as_dot(None));
-    h.show(DotFormatter::display(), "hypergraph_concept")?
-        .wait()?;
+    let (mut child, png_path) = h.show(DotFormatter::display(), "hypergraph_concept")?;
+    child.wait()?;
 
     // Copy to documenation folder
     fs::create_dir_all("images")?;
-    fs::copy(
-        "target/ferret_hypergraph/png/hypergraph_concept.png",
-        "images/hypergraph_concept.png",
-    )?;
+    fs::copy(png_path, "images/hypergraph_concept.png")?;
 
     Ok(())
 }
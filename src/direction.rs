@@ -10,6 +10,11 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// Return both directions, `Outgoing` first.
+    pub fn all() -> [Direction; 2] {
+        [Direction::Outgoing, Direction::Incoming]
+    }
+
     /// Return the opposite `Direction`.
     pub fn opposite(self) -> Direction {
         match self {
@@ -126,14 +126,14 @@ impl<N, E, H, L, Id> Element<N, E, H, L, Id> {
     }
     pub fn source(&self) -> Option<&Id> {
         match self {
-            Element::Link { source, .. } => Some(&source),
+            Element::Link { source, .. } => Some(source),
             Element::Edge { .. } | Element::Hypergraph { .. } | Element::Node { .. } => None,
         }
     }
 
     pub fn target(&self) -> Option<&Id> {
         match self {
-            Element::Link { target, .. } => Some(&target),
+            Element::Link { target, .. } => Some(target),
             Element::Edge { .. } | Element::Hypergraph { .. } | Element::Node { .. } => None,
         }
     }
@@ -264,15 +264,15 @@ impl<N, E, H, L, Id> ElementExt<N, E, H, L, Id> {
 
     pub fn source(&self) -> Option<&Id> {
         match self {
-            ElementExt::Edge { source, .. } => Some(&source),
-            ElementExt::Link { source, .. } => Some(&source),
+            ElementExt::Edge { source, .. } => Some(source),
+            ElementExt::Link { source, .. } => Some(source),
             ElementExt::Hypergraph { .. } | ElementExt::Node { .. } => None,
         }
     }
     pub fn target(&self) -> Option<&Id> {
         match self {
-            ElementExt::Edge { target, .. } => Some(&target),
-            ElementExt::Link { target, .. } => Some(&target),
+            ElementExt::Edge { target, .. } => Some(target),
+            ElementExt::Link { target, .. } => Some(target),
             ElementExt::Hypergraph { .. } | ElementExt::Node { .. } => None,
         }
     }
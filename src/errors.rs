@@ -1,6 +1,10 @@
 use thiserror::Error;
 
 /// # Basic
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("The edge {0:?} would be left without source or target by moving link {1:?}.")]
+pub struct EdgeNeedsLink(pub Vec<usize>, pub Vec<usize>);
+
 #[derive(Copy, Debug, Error, Clone, PartialEq, Eq)]
 #[error("Source can not be empty.")]
 pub struct EmptySource;
@@ -13,6 +17,10 @@ pub struct EmptyTarget;
 #[error("The link is incoherent (location {0:?}, source {1:?}, target {2:?}).")]
 pub struct IncoherentLink(pub Vec<usize>, pub Vec<usize>, pub Vec<usize>);
 
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("The element with id {0:?} is a link.")]
+pub struct IsLink(pub Vec<usize>);
+
 #[derive(Copy, Debug, Error, Clone, PartialEq, Eq)]
 #[error("Failed to convert to ElementLinkable because element is a link.")]
 pub struct LinkPresent;
@@ -25,6 +33,10 @@ pub struct LinkSource(pub Vec<usize>);
 #[error("Target can not be a link.")]
 pub struct LinkTarget(pub Vec<usize>);
 
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("The hypergraph {0:?} can not be moved inside itself (location {1:?}).")]
+pub struct MoveIntoItself(pub Vec<usize>, pub Vec<usize>);
+
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 #[error("There is no edge with id {0:?}.")]
 pub struct NoEdge(pub Vec<usize>);
@@ -49,6 +61,14 @@ pub struct NoLink(pub Vec<usize>);
 #[error("There is no node with id {0:?}.")]
 pub struct NoNode(pub Vec<usize>);
 
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("The element {1:?} is not an endpoint of link {0:?}.")]
+pub struct NotEndpoint(pub Vec<usize>, pub Vec<usize>);
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("There is no link from {0:?} to {1:?}.")]
+pub struct NotLinked(pub Vec<usize>, pub Vec<usize>);
+
 #[derive(Copy, Debug, Error, Clone, PartialEq, Eq)]
 #[error("The method does not apply to the root hypergraph.")]
 pub struct RootHypergraph;
@@ -61,14 +81,21 @@ pub struct Unlinkable(pub Vec<usize>, pub Vec<usize>);
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 #[error("Failed to add element.")]
 pub enum AddError {
+    EdgeNeedsLink(#[from] EdgeNeedsLink),
     EmptySource(#[from] EmptySource),
     EmptyTarget(#[from] EmptyTarget),
     IncoherentLink(#[from] IncoherentLink),
     LinkSource(#[from] LinkSource),
     LinkTarget(#[from] LinkTarget),
+    MoveIntoItself(#[from] MoveIntoItself),
+    NoEdge(#[from] NoEdge),
+    NoElement(#[from] NoElement),
+    NoLink(#[from] NoLink),
     NoLocation(#[from] NoHypergraph),
     NoSource(#[source] NoElementLinkable),
     NoTarget(#[source] NoElementLinkable),
+    NotLinked(#[from] NotLinked),
+    RootHypergraph(#[from] RootHypergraph),
     Unlinkable(#[from] Unlinkable),
 }
 
@@ -86,12 +113,14 @@ pub enum FindError {
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 #[error("Failed to get an element.")]
 pub enum GetError {
+    IsLink(#[from] IsLink),
     NoEdge(#[from] NoEdge),
     NoElement(#[from] NoElement),
     NoElementLinkable(#[from] NoElementLinkable),
     NoHypergraph(#[from] NoHypergraph),
     NoLink(#[from] NoLink),
     NoNode(#[from] NoNode),
+    NotEndpoint(#[from] NotEndpoint),
     RootHypergraph(#[from] RootHypergraph),
 }
 
@@ -115,3 +144,65 @@ pub enum SetError {
     NoLink(#[from] NoLink),
     NoNode(#[from] NoNode),
 }
+
+/// # Unified
+///
+/// Any error of the compound kinds, for functions that mix several operations.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("Failed to operate on the hypergraph.")]
+pub enum Error {
+    Add(#[from] AddError),
+    Find(#[from] FindError),
+    Get(#[from] GetError),
+    Remove(#[from] RemoveError),
+    Set(#[from] SetError),
+}
+
+/// # Validation
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("The link {1:?} is listed by element {0:?} but does not exist.")]
+    DanglingNeighbor(Vec<usize>, Vec<usize>),
+    #[error("The edge {0:?} has less than two links.")]
+    EdgeWithFewLinks(Vec<usize>),
+    #[error("The source {1:?} of link {0:?} is not a linkable element.")]
+    InvalidLinkSource(Vec<usize>, Vec<usize>),
+    #[error("The target {1:?} of link {0:?} is not a linkable element.")]
+    InvalidLinkTarget(Vec<usize>, Vec<usize>),
+    #[error("The link {0:?} is not listed by its endpoint {1:?}.")]
+    MissingNeighbor(Vec<usize>, Vec<usize>),
+    #[error("The next id of hypergraph {0:?} is not bigger than all its local ids.")]
+    NextIdTooSmall(Vec<usize>),
+    #[error("The link {1:?} is listed by element {0:?} with the wrong direction.")]
+    WrongDirection(Vec<usize>, Vec<usize>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hypergraph;
+
+    #[test]
+    fn error_from_compound() {
+        fn copy_first_node(
+            h: &mut Hypergraph<&'static str, ()>,
+            location: &[usize],
+        ) -> Result<Vec<usize>, Error> {
+            let value = *h.node_value([0])?;
+            let id = h.add_node_in(value, location)?;
+            Ok(id)
+        }
+
+        let mut h = Hypergraph::new();
+        assert_eq!(
+            copy_first_node(&mut h, &[]),
+            Err(Error::Get(GetError::NoNode(NoNode(vec![0]))))
+        );
+        h.add_node("zero");
+        assert_eq!(
+            copy_first_node(&mut h, &[1]),
+            Err(Error::Add(AddError::NoLocation(NoHypergraph(vec![1]))))
+        );
+        assert_eq!(copy_first_node(&mut h, &[]), Ok(vec![1]));
+    }
+}
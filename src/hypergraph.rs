@@ -1,21 +1,69 @@
-use core::fmt::Debug;
+use core::fmt::{self, Debug};
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{direction::Direction, elements::*, errors, traits::HypergraphClass};
 
 mod add;
+mod analysis;
 mod classes;
 mod clear;
+#[cfg(feature = "serde_json")]
+mod compact;
 mod extend;
 mod find;
 mod get;
+#[cfg(feature = "petgraph")]
+mod interop;
+#[cfg(feature = "journal")]
+mod journal;
+mod matrices;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod patch;
+mod removal;
 mod remove;
 mod set;
 mod transform;
+mod validate;
 pub mod visualize;
 
+pub use analysis::Reachability;
 pub use classes::{Main, Sub};
+#[cfg(feature = "petgraph")]
+pub use interop::EdgeMapping;
+#[cfg(feature = "journal")]
+pub use journal::Journaled;
+pub use patch::GraphPatch;
+
+/// Links of a linkable element: the id of each link and its direction (in absolute format).
+pub type ElementLinks = Vec<(Vec<usize>, Direction)>;
+
+/// Value of a link, with the ids of its source and target (in absolute format).
+pub type LinkEntry<L> = (Option<L>, Vec<usize>, Vec<usize>);
+
+/// Subhypergraph, with its links (in absolute format).
+pub type SubhypergraphEntry<N, E, H, L> = (Hypergraph<N, E, H, L, Sub>, ElementLinks);
+
+/// Hypergraph referred by an id: either the main one or one of its subhypergraphs.
+pub type HypergraphRef<'a, N, E, H, L, Ty> =
+    HypergraphEnum<&'a Hypergraph<N, E, H, L, Ty>, &'a Hypergraph<N, E, H, L, Sub>>;
+
+/// Mutable version of [`HypergraphRef`].
+pub type HypergraphRefMut<'a, N, E, H, L, Ty> =
+    HypergraphEnum<&'a mut Hypergraph<N, E, H, L, Ty>, &'a mut Hypergraph<N, E, H, L, Sub>>;
+
+/// Sources and targets of an edge.
+pub type EdgeEndpoints<'a> = (Vec<&'a Vec<usize>>, Vec<&'a Vec<usize>>);
+
+/// Ids of all nodes, ids of all edges and the incidence matrix between them.
+pub type IncidenceMatrix = (Vec<Vec<usize>>, Vec<Vec<usize>>, Vec<Vec<i8>>);
+
+/// Element with its id, owning its value and the ids it refers to.
+pub type OwnedElement<N, E, H, L> = (Vec<usize>, Element<N, E, H, L, Vec<usize>>);
+
+/// New id of each element, indexed by its old id.
+pub type IdMapping = std::collections::HashMap<Vec<usize>, Vec<usize>>;
 
 /// Directed-hyper-multi-graphs.
 ///
@@ -47,20 +95,28 @@ pub use classes::{Main, Sub};
 ///
 /// Indices are represented by `Vec<usize>` by default. They are stable except upon usage
 /// of any method under [`Optimization`](#optimization) (like [`shrink_to_fit`]).
+/// In particular, removing an element does not change the id of any other element,
+/// nor the order in which they are visited.
 ///
 /// # Contents
 ///
 /// - [`Add`](#add)
+/// - [`Analysis`](#analysis)
 /// - [`Create`](#create)
 /// - [`Clear`](#clear)
 /// - [`Extend`](#extend)
 /// - [`Find`](#find)
 /// - [`Get`](#get)
 /// - [`Inform`](#inform)
+/// - [`Interoperability`](#interoperability)
+/// - [`Matrices`](#matrices)
 /// - [`Optimization`](#optimization)
+/// - [`Parallel`](#parallel)
+/// - [`Patch`](#patch)
 /// - [`Remove`](#remove)
 /// - [`Set`](#set)
 /// - [`Transform`](#transform)
+/// - [`Validate`](#validate)
 /// - [`Visualize`](#visualize)
 //
 // # Note
@@ -68,23 +124,70 @@ pub use classes::{Main, Sub};
 // You might want to change Vec by SmallVec in the future
 // and let the user decide the storage capacity (ie. how many nested structures are there).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawHypergraph<N, E, H, L, Ty>")]
 pub struct Hypergraph<N, E, H = (), L = (), Ty = Main> {
     /// Value of the hypergraph as a whole.
     value: Option<H>,
     /// nodes: their weight and links (in absolute format)
-    nodes: IndexMap<usize, (N, Vec<(Vec<usize>, Direction)>)>,
+    nodes: IndexMap<usize, (N, ElementLinks)>,
     /// edges: weight and links (in absolute format)
-    edges: IndexMap<usize, (E, Vec<(Vec<usize>, Direction)>)>, // This vector always has at least two elements
+    edges: IndexMap<usize, (E, ElementLinks)>, // This vector always has at least two elements
     /// links: weight, source and target ids (in absolute format)
-    links: IndexMap<usize, (Option<L>, Vec<usize>, Vec<usize>)>, // Links have no neighbors
+    links: IndexMap<usize, LinkEntry<L>>, // Links have no neighbors
     /// subhypergraps: subhypergraph and links
-    hypergraphs: IndexMap<usize, (Hypergraph<N, E, H, L, Sub>, Vec<(Vec<usize>, Direction)>)>,
+    hypergraphs: IndexMap<usize, SubhypergraphEntry<N, E, H, L>>,
     /// Counter for the next id when adding elements. It also serves as an upper bound on the number of elements.
     next_id: usize,
     /// Type (either Main or Sub)
     class: Ty,
 }
 
+/// Deserialization format of `Hypergraph`, before its next id is checked.
+///
+/// A `next_id` which is not bigger than all local ids (for example, after manual edits of
+/// the serialized data) is recomputed, so that adding elements never overrides existing ones.
+#[derive(Deserialize)]
+struct RawHypergraph<N, E, H, L, Ty> {
+    value: Option<H>,
+    nodes: IndexMap<usize, (N, ElementLinks)>,
+    edges: IndexMap<usize, (E, ElementLinks)>,
+    links: IndexMap<usize, LinkEntry<L>>,
+    hypergraphs: IndexMap<usize, SubhypergraphEntry<N, E, H, L>>,
+    next_id: usize,
+    class: Ty,
+}
+
+impl<N, E, H, L, Ty> From<RawHypergraph<N, E, H, L, Ty>> for Hypergraph<N, E, H, L, Ty> {
+    fn from(raw: RawHypergraph<N, E, H, L, Ty>) -> Self {
+        // Subhypergraphs are already checked when deserialized
+        let next_id = checked_next_id(
+            raw.next_id,
+            raw.edges
+                .keys()
+                .chain(raw.hypergraphs.keys())
+                .chain(raw.links.keys())
+                .chain(raw.nodes.keys()),
+        );
+        Hypergraph {
+            value: raw.value,
+            nodes: raw.nodes,
+            edges: raw.edges,
+            links: raw.links,
+            hypergraphs: raw.hypergraphs,
+            next_id,
+            class: raw.class,
+        }
+    }
+}
+
+/// Returns `next_id` if it is bigger than all `local_ids`, or the biggest local id plus one otherwise.
+fn checked_next_id<'a>(next_id: usize, local_ids: impl Iterator<Item = &'a usize>) -> usize {
+    match local_ids.max() {
+        Some(&max_local_id) if max_local_id >= next_id => max_local_id + 1,
+        _ => next_id,
+    }
+}
+
 /// Wrapper for ease of implementation.
 ///
 /// It is not meant to be part of the public API.
@@ -117,10 +220,7 @@ impl<'a, N, E, H, L, Ty>
             HypergraphEnum::Sub(h) => h.contains(id),
         }
     }
-    pub fn links_of(
-        &self,
-        id: impl AsRef<[usize]>,
-    ) -> Result<&'a Vec<(Vec<usize>, Direction)>, errors::GetError> {
+    pub fn links_of(&self, id: impl AsRef<[usize]>) -> Result<&'a ElementLinks, errors::GetError> {
         match self {
             HypergraphEnum::Original(h) => h.links_of(id),
             HypergraphEnum::Sub(h) => h.links_of(id),
@@ -134,30 +234,35 @@ impl<'a, N, E, H, L, Ty>
         }
     }
 
-    pub fn raw_edges(&self) -> &'a IndexMap<usize, (E, Vec<(Vec<usize>, Direction)>)> {
+    pub fn raw_edges(&self) -> &'a IndexMap<usize, (E, ElementLinks)> {
         match self {
             HypergraphEnum::Original(h) => h.raw_edges(),
             HypergraphEnum::Sub(h) => h.raw_edges(),
         }
     }
 
-    pub fn raw_links(&self) -> &'a IndexMap<usize, (Option<L>, Vec<usize>, Vec<usize>)> {
+    pub fn raw_links(&self) -> &'a IndexMap<usize, LinkEntry<L>> {
         match self {
             HypergraphEnum::Original(h) => h.raw_links(),
             HypergraphEnum::Sub(h) => h.raw_links(),
         }
     }
 
-    pub fn raw_hypergraphs(
-        &self,
-    ) -> &'a IndexMap<usize, (Hypergraph<N, E, H, L, Sub>, Vec<(Vec<usize>, Direction)>)> {
+    pub fn raw_hypergraphs(&self) -> &'a IndexMap<usize, SubhypergraphEntry<N, E, H, L>> {
         match self {
             HypergraphEnum::Original(h) => h.raw_hypergraphs(),
             HypergraphEnum::Sub(h) => h.raw_hypergraphs(),
         }
     }
 
-    pub fn raw_nodes(&self) -> &'a IndexMap<usize, (N, Vec<(Vec<usize>, Direction)>)> {
+    pub fn next_local_id(&self) -> usize {
+        match self {
+            HypergraphEnum::Original(h) => h.next_local_id(),
+            HypergraphEnum::Sub(h) => h.next_local_id(),
+        }
+    }
+
+    pub fn raw_nodes(&self) -> &'a IndexMap<usize, (N, ElementLinks)> {
         match self {
             HypergraphEnum::Original(h) => h.raw_nodes(),
             HypergraphEnum::Sub(h) => h.raw_nodes(),
@@ -200,30 +305,28 @@ impl<'a, N, E, H, L, Ty>
         }
     }
 
-    pub fn raw_edges_mut(self) -> &'a mut IndexMap<usize, (E, Vec<(Vec<usize>, Direction)>)> {
+    pub fn raw_edges_mut(self) -> &'a mut IndexMap<usize, (E, ElementLinks)> {
         match self {
             HypergraphEnum::Original(h) => h.raw_edges_mut(),
             HypergraphEnum::Sub(h) => h.raw_edges_mut(),
         }
     }
 
-    pub fn raw_links_mut(self) -> &'a mut IndexMap<usize, (Option<L>, Vec<usize>, Vec<usize>)> {
+    pub fn raw_links_mut(self) -> &'a mut IndexMap<usize, LinkEntry<L>> {
         match self {
             HypergraphEnum::Original(h) => h.raw_links_mut(),
             HypergraphEnum::Sub(h) => h.raw_links_mut(),
         }
     }
 
-    pub fn raw_hypergraphs_mut(
-        self,
-    ) -> &'a mut IndexMap<usize, (Hypergraph<N, E, H, L, Sub>, Vec<(Vec<usize>, Direction)>)> {
+    pub fn raw_hypergraphs_mut(self) -> &'a mut IndexMap<usize, SubhypergraphEntry<N, E, H, L>> {
         match self {
             HypergraphEnum::Original(h) => h.raw_hypergraphs_mut(),
             HypergraphEnum::Sub(h) => h.raw_hypergraphs_mut(),
         }
     }
 
-    pub fn raw_nodes_mut(self) -> &'a mut IndexMap<usize, (N, Vec<(Vec<usize>, Direction)>)> {
+    pub fn raw_nodes_mut(self) -> &'a mut IndexMap<usize, (N, ElementLinks)> {
         match self {
             HypergraphEnum::Original(h) => h.raw_nodes_mut(),
             HypergraphEnum::Sub(h) => h.raw_nodes_mut(),
@@ -284,6 +387,30 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
     }
 }
 
+impl<N, E, H, L, Ty: HypergraphClass> Default for Hypergraph<N, E, H, L, Ty> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compact summary of the hypergraph, counting elements in all levels.
+///
+/// For a full dump, use `Debug` instead.
+impl<N, E, H, L, Ty> fmt::Display for Hypergraph<N, E, H, L, Ty> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = self.counts();
+        write!(
+            f,
+            "Hypergraph{{ nodes: {}, edges: {}, links: {}, subgraphs: {}, depth: {} }}",
+            counts.nodes,
+            counts.edges,
+            counts.links,
+            counts.hypergraphs - 1,
+            self.depth()
+        )
+    }
+}
+
 /// # Create
 ///
 /// A graph that can be created.
@@ -322,6 +449,13 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
         }
     }
 
+    /// Creates an empty hypergraph whose value, as a whole, is `value`.
+    pub fn with_value(value: impl Into<Option<H>>) -> Self {
+        let mut hypergraph = Self::new();
+        hypergraph.set_value(value);
+        hypergraph
+    }
+
     /// Reserve `additional` in all underlying maps of `self`.
     pub fn reserve(&mut self, additional: usize) -> &mut Self {
         self.reserve_edges(additional)
@@ -330,6 +464,29 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
             .reserve_nodes(additional)
     }
 
+    /// Reserve `additional` in all underlying maps of the (sub-)hypergraph `location`.
+    ///
+    /// An empty `location` refers to `self`.
+    ///
+    /// # Errors
+    ///
+    /// If `location` does not correspond to a hypergraph.
+    pub fn reserve_in(
+        &mut self,
+        location: impl AsRef<[usize]>,
+        additional: usize,
+    ) -> Result<&mut Self, errors::GetError> {
+        match self.hypergraph_at_mut(location)? {
+            HypergraphEnum::Original(hypergraph) => {
+                hypergraph.reserve(additional);
+            }
+            HypergraphEnum::Sub(hypergraph) => {
+                hypergraph.reserve(additional);
+            }
+        }
+        Ok(self)
+    }
+
     pub fn reserve_edges(&mut self, additional: usize) -> &mut Self {
         self.edges.reserve(additional);
         self
@@ -350,6 +507,18 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
     }
 }
 
+/// Number of elements of each kind in a hypergraph, counting all nesting levels.
+///
+/// Fields are ordered alphabetically, as in [`Hypergraph::capacities`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Counts {
+    pub edges: usize,
+    /// Number of hypergraphs, including the hypergraph itself.
+    pub hypergraphs: usize,
+    pub links: usize,
+    pub nodes: usize,
+}
+
 /// # Inform
 ///
 /// Various information about the hypergraph.
@@ -366,6 +535,24 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         )
     }
 
+    /// Returns the current capacity of the underlying `Map`s of the (sub-)hypergraph `location`.
+    ///
+    /// The output is ordered as in [`capacities`](#method.capacities).
+    ///
+    /// # Errors
+    ///
+    /// If `location` does not correspond to a hypergraph.
+    pub fn capacities_in(
+        &self,
+        location: impl AsRef<[usize]>,
+    ) -> Result<(usize, usize, usize, usize), errors::GetError> {
+        let capacities = match self.hypergraph_at(location)? {
+            HypergraphEnum::Original(hypergraph) => hypergraph.capacities(),
+            HypergraphEnum::Sub(hypergraph) => hypergraph.capacities(),
+        };
+        Ok(capacities)
+    }
+
     /// Returns `true` if `id` corresponds to an existing element of `self`
     /// and it can be linked (node, edge or hypergraph).
     pub fn contains_linkable(&self, id: impl AsRef<[usize]>) -> bool {
@@ -386,7 +573,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             }
             _ => {
                 let local_id = id.pop().unwrap(); // Never fails since id is non empty.
-                let hypergraph = match self.hypergraph(id) {
+                let hypergraph = match self.hypergraph_at(id) {
                     Ok(h) => h,
                     Err(_) => return false,
                 };
@@ -401,7 +588,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             0 => false,
             _ => {
                 let local_id = id.pop().unwrap(); // Never fails since id is non empty.
-                let hypergraph = match self.hypergraph(id) {
+                let hypergraph = match self.hypergraph_at(id) {
                     Ok(h) => h,
                     Err(_) => return false,
                 };
@@ -416,7 +603,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             0 => false,
             _ => {
                 let local_id = id.pop().unwrap(); // Never fails since id is non empty.
-                let hypergraph = match self.hypergraph(id) {
+                let hypergraph = match self.hypergraph_at(id) {
                     Ok(h) => h,
                     Err(_) => return false,
                 };
@@ -431,7 +618,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             0 => false,
             _ => {
                 let local_id = id.pop().unwrap(); // Never fails since id is non empty.
-                let hypergraph = match self.hypergraph(id) {
+                let hypergraph = match self.hypergraph_at(id) {
                     Ok(h) => h,
                     Err(_) => return false,
                 };
@@ -467,6 +654,35 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         }
     }
 
+    /// Returns the number of elements of each kind, counting all nesting levels.
+    ///
+    /// As in [`hypergraph_count`](#method.hypergraph_count), the hypergraph itself is counted.
+    pub fn counts(&self) -> Counts {
+        let mut counts = Counts {
+            edges: self.edge_count(),
+            hypergraphs: 1,
+            links: self.link_count(),
+            nodes: self.node_count(),
+        };
+        for (subhypergraph, _) in self.raw_hypergraphs().values() {
+            let subcounts = subhypergraph.counts();
+            counts.edges += subcounts.edges;
+            counts.hypergraphs += subcounts.hypergraphs;
+            counts.links += subcounts.links;
+            counts.nodes += subcounts.nodes;
+        }
+        counts
+    }
+
+    /// Returns the number of links of the linkable element `id`, in any direction.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
+    pub fn degree(&self, id: impl AsRef<[usize]>) -> Result<usize, errors::GetError> {
+        Ok(self.links_of(id)?.len())
+    }
+
     /// Returns the number of levels of nested hypergraphs.
     pub fn depth(&self) -> usize {
         let mut recursive = 0;
@@ -476,11 +692,58 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         recursive + 1
     }
 
-    /// Return the number of edges in the graph.
+    /// Returns the number of hypergraphs enclosing the element `id`,
+    /// or `None` if `id` does not refer to any element.
+    ///
+    /// The root has depth zero and elements of the top level have depth one.
+    pub fn depth_of(&self, id: impl AsRef<[usize]>) -> Option<usize> {
+        let id = id.as_ref();
+        if self.contains(id) {
+            Some(id.len())
+        } else {
+            None
+        }
+    }
+
+    /// Return the number of edges in the top level of the graph.
+    ///
+    /// Edges of nested hypergraphs are not counted, see [`edge_count_recursive`](#method.edge_count_recursive).
     pub fn edge_count(&self) -> usize {
         self.edges.len()
     }
 
+    /// Return the number of edges in the graph, counting all nesting levels.
+    pub fn edge_count_recursive(&self) -> usize {
+        self.counts().edges
+    }
+
+    /// Returns the total number of elements, counting all nesting levels (including itself).
+    ///
+    /// This is the number of ids returned by [`ids`](#method.ids).
+    pub fn element_count(&self) -> usize {
+        let counts = self.counts();
+        counts.edges + counts.hypergraphs + counts.links + counts.nodes
+    }
+
+    /// Returns the number of elements of each kind, counting all nesting levels (including itself).
+    ///
+    /// The output is ordered alphabetically: edges, hypergraphs, links, nodes.
+    /// It is computed by visiting all [`ids`](#method.ids), see [`counts`](#method.counts)
+    /// for a faster alternative.
+    pub fn element_kind_counts(&self) -> (usize, usize, usize, usize) {
+        let mut counts = (0, 0, 0, 0);
+        for id in self.ids() {
+            let element_type = self.element_type(id).unwrap(); // Never fails since id refers to an element
+            match element_type {
+                ElementType::Edge => counts.0 += 1,
+                ElementType::Hypergraph => counts.1 += 1,
+                ElementType::Link => counts.2 += 1,
+                ElementType::Node => counts.3 += 1,
+            }
+        }
+        counts
+    }
+
     /// Returns a bound on valid ids.
     ///
     /// All valid ids are strictly smaller than the output (in lexicographic order).
@@ -495,6 +758,19 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         }
     }
 
+    /// Returns the number of incoming links of the linkable element `id`.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
+    pub fn in_degree(&self, id: impl AsRef<[usize]>) -> Result<usize, errors::GetError> {
+        let links = self.links_of(id)?;
+        Ok(links
+            .iter()
+            .filter(|(_, direction)| direction == &Direction::Incoming)
+            .count())
+    }
+
     /// Returns `true` if there are no nodes or hypergraphs.
     ///
     /// # Esamples
@@ -509,20 +785,74 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         self.raw_nodes().is_empty() && self.raw_hypergraphs().is_empty()
     }
 
-    /// Return the number of hypergraphs in the graph (including itself).
+    /// Return the number of hypergraphs in the top level of the graph (including itself).
+    ///
+    /// Hypergraphs nested in subhypergraphs are not counted, see [`hypergraph_count_recursive`](#method.hypergraph_count_recursive).
     pub fn hypergraph_count(&self) -> usize {
         1 + self.hypergraphs.len()
     }
 
-    /// Return the number of links in the graph.
+    /// Return the number of hypergraphs in the graph (including itself), counting all nesting levels.
+    pub fn hypergraph_count_recursive(&self) -> usize {
+        self.counts().hypergraphs
+    }
+
+    /// Returns the number of outgoing links of the linkable element `id`.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
+    pub fn out_degree(&self, id: impl AsRef<[usize]>) -> Result<usize, errors::GetError> {
+        let links = self.links_of(id)?;
+        Ok(links
+            .iter()
+            .filter(|(_, direction)| direction == &Direction::Outgoing)
+            .count())
+    }
+
+    /// Return the number of links in the top level of the graph.
+    ///
+    /// Links of nested hypergraphs are not counted, see [`link_count_recursive`](#method.link_count_recursive).
     pub fn link_count(&self) -> usize {
         self.links.len()
     }
 
-    /// Return the number of nodes in the graph.
+    /// Return the number of links in the graph, counting all nesting levels.
+    pub fn link_count_recursive(&self) -> usize {
+        self.counts().links
+    }
+
+    /// Returns the number of neighbors of `id` in `direction`, counted with multiplicity.
+    ///
+    /// This is the number of items of [`neighbors_directed`](#method.neighbors_directed),
+    /// computed without walking the hypergraph.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
+    pub fn neighbors_count(
+        &self,
+        id: impl AsRef<[usize]>,
+        direction: Direction,
+    ) -> Result<usize, errors::GetError> {
+        let links = self.links_of(id)?;
+        Ok(links
+            .iter()
+            .filter(|(_, link_direction)| link_direction == &direction)
+            .count())
+    }
+
+    /// Return the number of nodes in the top level of the graph.
+    ///
+    /// Nodes of nested hypergraphs are not counted, see [`node_count_recursive`](#method.node_count_recursive).
     pub fn node_count(&self) -> usize {
         self.nodes.len()
     }
+
+    /// Return the number of nodes in the graph, counting all nesting levels.
+    pub fn node_count_recursive(&self) -> usize {
+        self.counts().nodes
+    }
 }
 
 impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
@@ -539,6 +869,161 @@ mod tests {
     use super::*;
     use test_case::test_case;
 
+    /// Hypergraph with every kind of element, at two levels, used across the test modules.
+    pub(crate) fn example() -> Hypergraph<&'static str, &'static str, &'static str, &'static str> {
+        let mut h = Hypergraph::new();
+        h.set_value("(main)hypergraph");
+        h.add_node("node");
+        h.add_node("node");
+        h.add_edge([0], [1], "edge").unwrap();
+        h.set_link_value([3], "link").unwrap();
+        h.add_node("node");
+        h.add_link([2], [5], "link").unwrap();
+        h.add_hypergraph("(sub)hypergraph");
+        h.add_node_in("node", [7]).unwrap();
+        h.add_node_in("node", [7]).unwrap();
+        h.add_edge_in([7, 0], [7, 1], "edge", [7]).unwrap();
+        h.add_link([2], [7, 0], "link").unwrap();
+        h.add_link([2], [7], "link").unwrap();
+        h
+    }
+
+    /// Asserts that both hypergraphs have the same ids, and the same values and links for each id.
+    ///
+    /// Values are compared through their `Debug` output, so that owned and borrowed values can be compared.
+    pub(crate) fn assert_same_elements<N, E, H, L, Ty, N2, E2, H2, L2, Ty2>(
+        one: &Hypergraph<N, E, H, L, Ty>,
+        other: &Hypergraph<N2, E2, H2, L2, Ty2>,
+    ) where
+        N: Debug,
+        E: Debug,
+        H: Debug,
+        L: Debug,
+        N2: Debug,
+        E2: Debug,
+        H2: Debug,
+        L2: Debug,
+    {
+        assert_eq!(
+            one.ids().collect::<Vec<_>>(),
+            other.ids().collect::<Vec<_>>()
+        );
+        for id in one.ids() {
+            assert_eq!(
+                format!("{:?}", one.element_value(&id)),
+                format!("{:?}", other.element_value(&id)),
+                "different values at {:?}",
+                id
+            );
+            if one.contains_linkable(&id) {
+                assert_eq!(one.links_of(&id), other.links_of(&id));
+            }
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn serde_round_trip() {
+        let h = example();
+        let json = serde_json::to_string(&h).unwrap();
+        let copy: Hypergraph<&str, &str, &str, &str> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(serde_json::to_string(&copy).unwrap(), json);
+        assert_same_elements(&copy, &h);
+        assert_eq!(copy.validate(), Ok(()));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn serde_next_id_drift() {
+        let mut h = example();
+        h.next_id = 1;
+        h.subhypergraph_mut([7]).unwrap().next_id = 0;
+        let json = serde_json::to_string(&h).unwrap();
+        let mut copy: Hypergraph<&str, &str, &str, &str> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(copy.next_local_id(), 10);
+        assert_eq!(copy.subhypergraph([7]).unwrap().next_local_id(), 5);
+        assert_eq!(copy.validate(), Ok(()));
+        assert_eq!(copy.add_node("new"), vec![10]);
+    }
+
+    #[test]
+    fn neighbors_count() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], ()).unwrap();
+
+        for id in h.ids().filter(|id| h.contains_linkable(id)) {
+            for &direction in &[Direction::Incoming, Direction::Outgoing] {
+                assert_eq!(
+                    h.neighbors_count(&id, direction),
+                    Ok(h.neighbors_directed(&id, direction).unwrap().count())
+                );
+            }
+        }
+        assert_eq!(h.neighbors_count([0], Direction::Outgoing), Ok(2));
+        assert!(h.neighbors_count([3], Direction::Outgoing).is_err());
+    }
+
+    #[test]
+    fn display() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        assert_eq!(
+            h.to_string(),
+            "Hypergraph{ nodes: 2, edges: 1, links: 2, subgraphs: 0, depth: 1 }"
+        );
+
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        assert_eq!(
+            h.to_string(),
+            "Hypergraph{ nodes: 3, edges: 1, links: 2, subgraphs: 1, depth: 2 }"
+        );
+    }
+
+    #[test]
+    fn element_kind_counts() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
+        h.add_link([2], [5, 0], "eleven").unwrap();
+        h.add_hypergraph_in("twelve", [5]).unwrap();
+        h.add_node_in("thirteen", [5, 5]).unwrap();
+
+        assert_eq!(h.element_kind_counts(), (2, 3, 5, 5));
+        let counts = h.counts();
+        assert_eq!(
+            h.element_kind_counts(),
+            (counts.edges, counts.hypergraphs, counts.links, counts.nodes)
+        );
+    }
+
+    #[test]
+    fn degree() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], ()).unwrap();
+        assert_eq!(h.degree([0]), Ok(2));
+        assert_eq!(h.in_degree([0]), Ok(0));
+        assert_eq!(h.out_degree([0]), Ok(2));
+        assert_eq!(h.in_degree([2]), Ok(2));
+        assert_eq!(h.out_degree([2]), Ok(1));
+        assert!(h.degree([3]).is_err());
+    }
+
     #[test_case(Hypergraph::<(), ()>::new(), 1; "no recursion")]
     #[test_case(
         {
@@ -565,8 +1050,106 @@ mod tests {
         assert_eq!(h.depth(), expected)
     }
 
+    #[test]
+    fn depth_of() {
+        let mut h = Hypergraph::<_, _, _, ()>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_hypergraph_in("seven", [5]).unwrap();
+        h.add_node_in("eight", [5, 1]).unwrap();
+
+        assert_eq!(h.depth_of([]), Some(0));
+        assert_eq!(h.depth_of([3]), Some(1));
+        assert_eq!(h.depth_of([5, 0]), Some(2));
+        assert_eq!(h.depth_of([5, 1, 0]), Some(3));
+        assert_eq!(h.depth_of([5, 2]), None);
+        assert_eq!(h.depth_of([6]), None);
+    }
+
+    #[test]
+    fn counts() {
+        let mut h = Hypergraph::<_, _, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
+        h.add_link([2], [5, 0], "eleven").unwrap();
+        h.add_hypergraph_in("twelve", [5]).unwrap();
+        h.add_node_in("thirteen", [5, 5]).unwrap();
+
+        assert_eq!(
+            h.counts(),
+            Counts {
+                edges: 2,
+                hypergraphs: 3,
+                links: 5,
+                nodes: 5,
+            }
+        );
+        assert_eq!(h.element_count(), 15);
+        assert_eq!(h.element_count(), h.ids().count());
+    }
+
+    #[test]
+    fn count_recursive() {
+        let mut h = Hypergraph::<_, _, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
+        h.add_link([2], [5, 0], "eleven").unwrap();
+        h.add_hypergraph_in("twelve", [5]).unwrap();
+        h.add_node_in("thirteen", [5, 5]).unwrap();
+
+        assert_eq!(h.node_count(), 2);
+        assert_eq!(h.node_count_recursive(), 5);
+        assert_eq!(h.edge_count(), 1);
+        assert_eq!(h.edge_count_recursive(), 2);
+        assert_eq!(h.link_count(), 3);
+        assert_eq!(h.link_count_recursive(), 5);
+        assert_eq!(h.hypergraph_count(), 2);
+        assert_eq!(h.hypergraph_count_recursive(), 3);
+    }
+
     #[test]
     fn new() {
         Hypergraph::<(), ()>::new();
     }
+
+    #[test]
+    fn reserve_in() {
+        let mut h = Hypergraph::<(), (), (), ()>::new();
+        h.add_hypergraph(());
+        h.add_hypergraph_in((), [0]).unwrap();
+        h.reserve_in([0, 0], 10).unwrap();
+
+        let (edges, hypergraphs, links, nodes) = h.capacities_in([0, 0]).unwrap();
+        assert!(edges >= 10);
+        assert!(hypergraphs >= 10);
+        assert!(links >= 10);
+        assert!(nodes >= 10);
+        assert_eq!(h.capacities_in([]), Ok(h.capacities()));
+        assert_eq!(
+            h.reserve_in([1], 10).err(),
+            Some(errors::GetError::NoHypergraph(errors::NoHypergraph(vec![
+                1
+            ])))
+        );
+    }
+
+    #[test]
+    fn with_value() {
+        let h = Hypergraph::<(), (), _>::with_value("root");
+        assert_eq!(h.value(), &Some("root"));
+        assert!(h.is_empty());
+    }
 }
@@ -10,7 +10,7 @@ use crate::{
 impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
     /// Adds an element to the top level.
     ///
-    /// If you want to specify a location, see method [`add_element_in`].
+    /// If you want to specify a location, see method [`add_element_in`](#method.add_element_in).
     ///
     /// # Errors
     ///
@@ -52,7 +52,9 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
             Err(errors::NoHypergraph(location.to_vec()))?
         }
         if element.is_node() | element.is_hypergraph() {
-            return Ok(self.add_element_in_unchecked(element, location));
+            let id = self.add_element_in_unchecked(element, location);
+            self.debug_assert_invariants(&self.debug_touched_ids(&id));
+            return Ok(id);
         }
         // Never fails since element is now either edge or link
         let global_source_id = element.source().unwrap();
@@ -60,7 +62,7 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
             Err(errors::EmptySource)?
         }
 
-        let source_element = match self.element_value(&global_source_id) {
+        let source_element = match self.element_value(global_source_id) {
             Err(_) => {
                 return Err(errors::AddError::NoSource(errors::NoElementLinkable(
                     global_source_id.to_vec(),
@@ -90,7 +92,7 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
             Err(errors::EmptyTarget)?;
         }
 
-        let target_element = match self.element_value(&global_target_id) {
+        let target_element = match self.element_value(global_target_id) {
             Err(_) => {
                 return Err(errors::AddError::NoSource(errors::NoElementLinkable(
                     global_target_id.to_vec(),
@@ -144,8 +146,8 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
                 false
             }
         }
-        let coherent_rule = contains_or_equals(location, &global_source_id)
-            && contains_or_equals(location, &global_target_id);
+        let coherent_rule = contains_or_equals(location, global_source_id)
+            && contains_or_equals(location, global_target_id);
         if !coherent_rule {
             Err(errors::IncoherentLink(
                 location.to_vec(),
@@ -155,7 +157,9 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
         }
 
         // Now the connection is valid
-        Ok(self.add_element_in_unchecked(element, location))
+        let id = self.add_element_in_unchecked(element, location);
+        self.debug_assert_invariants(&self.debug_touched_ids(&id));
+        Ok(id)
     }
 
     /// Adds an element.
@@ -175,7 +179,7 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
         let location = location.as_ref();
         match element_ext {
             ElementExt::Node { .. } | ElementExt::Hypergraph { .. } => {
-                let mut hypergraph = self.hypergraph_mut(location).unwrap();
+                let mut hypergraph = self.hypergraph_at_mut(location).unwrap();
                 let local_id = hypergraph.add_local_element(element_ext.into());
                 let mut new_element_id = location.to_vec();
                 new_element_id.push(local_id);
@@ -190,7 +194,7 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
                 source,
                 target,
             } => {
-                let mut hypergraph = self.hypergraph_mut(location).unwrap();
+                let mut hypergraph = self.hypergraph_at_mut(location).unwrap();
                 // Add edge
                 let mut new_edge_id = location.to_vec();
                 let edge_local_id = hypergraph.add_local_element(Element::Edge { value });
@@ -221,14 +225,14 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
                     .unwrap()
                     .push((link_target_id.clone(), Direction::Incoming));
                 // Return new edge id
-                return new_edge_id;
+                new_edge_id
             }
             ElementExt::Link {
                 value,
                 source,
                 target,
             } => {
-                let mut hypergraph = self.hypergraph_mut(location).unwrap();
+                let mut hypergraph = self.hypergraph_at_mut(location).unwrap();
 
                 // Add link
                 let mut new_link_id = location.to_vec();
@@ -252,7 +256,7 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
                         (new_link_id.clone(), Direction::Incoming),
                     );
                 // Return link id
-                return new_link_id;
+                new_link_id
             }
             ElementExt::Node { .. } | ElementExt::Hypergraph { .. } => unreachable!(),
         }
@@ -260,6 +264,8 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
 
     /// Adds an edge in the top level.
     ///
+    /// If you want to specify a location, see method [`add_edge_in`](#method.add_edge_in).
+    ///
     /// # Remarks
     ///
     /// The returned `Vec<usize>` is the id of the new edge. But in the process of adding this edge,
@@ -308,6 +314,8 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
     }
 
     /// Adds a hypergraph in the top level.
+    ///
+    /// If you want to specify a location, see method [`add_hypergraph_in`](#method.add_hypergraph_in).
     pub fn add_hypergraph(&mut self, value: impl Into<Option<H>>) -> Vec<usize> {
         self.add_hypergraph_in(value, []).unwrap()
     }
@@ -333,6 +341,8 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
 
     /// Adds a link in the top level.
     ///
+    /// If you want to specify a location, see method [`add_link_in`](#method.add_link_in).
+    ///
     /// # Errors
     ///
     /// If `source` or `target` do not correspond to linkable elements.
@@ -369,6 +379,8 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
     }
 
     /// Adds a node in the top level.
+    ///
+    /// If you want to specify a location, see method [`add_node_in`](#method.add_node_in).
     pub fn add_node(&mut self, value: N) -> Vec<usize> {
         self.add_node_in(value, []).unwrap()
     }
@@ -389,6 +401,61 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
         let element = ElementExt::Node { value };
         self.add_element_in(element, location)
     }
+
+    /// Adds several nodes to `self`, returning their ids in order.
+    ///
+    /// `location` is identifies the hypergraph where the nodes will be added
+    /// and it is checked only once.
+    /// An empty `location` means the main hypergraph.
+    ///
+    /// # Errors
+    ///
+    /// If location does not correspond to a hypergraph. In this case, no node is added.
+    pub fn add_nodes(
+        &mut self,
+        values: impl IntoIterator<Item = N>,
+        location: impl AsRef<[usize]>,
+    ) -> Result<Vec<Vec<usize>>, errors::AddError> {
+        let location = location.as_ref();
+        let mut hypergraph = match self.hypergraph_at_mut(location) {
+            Ok(hypergraph) => hypergraph,
+            Err(_) => Err(errors::NoHypergraph(location.to_vec()))?,
+        };
+        let mut new_ids = Vec::new();
+        for value in values {
+            let local_id = hypergraph.add_local_element(Element::Node { value });
+            let mut new_id = location.to_vec();
+            new_id.push(local_id);
+            new_ids.push(new_id);
+        }
+        Ok(new_ids)
+    }
+
+    /// Returns the id of a link in `location` from `source` to `target` with value `value`,
+    /// adding it only if there is none.
+    ///
+    /// The existing link is the one found by [`find_link_id`](#method.find_link_id).
+    /// This makes repeated calls idempotent, avoiding duplicated links.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`add_link_in`](#method.add_link_in), if the link must be added.
+    pub fn ensure_link(
+        &mut self,
+        source: impl AsRef<[usize]>,
+        target: impl AsRef<[usize]>,
+        value: impl Into<Option<L>>,
+        location: impl AsRef<[usize]>,
+    ) -> Result<Vec<usize>, errors::AddError>
+    where
+        L: PartialEq,
+    {
+        let value = value.into();
+        if let Ok(id) = self.find_link_id(&source, &target, value.as_ref(), &location) {
+            return Ok(id);
+        }
+        self.add_link_in(source, target, value, location)
+    }
 }
 
 #[cfg(test)]
@@ -396,6 +463,24 @@ mod tests {
     use super::*;
     use test_case::test_case;
 
+    #[test]
+    fn add_nodes() {
+        let mut h = Hypergraph::<&str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_hypergraph("one");
+        h.add_node_in("zero", [1]).unwrap();
+
+        let ids = h.add_nodes(vec!["one", "two", "three"], [1]).unwrap();
+        assert_eq!(ids, vec![vec![1, 1], vec![1, 2], vec![1, 3]]);
+        assert_eq!(h.node_value([1, 2]).unwrap(), &"two");
+        assert_eq!(h.add_nodes(vec!["four"], []).unwrap(), vec![vec![2]]);
+        assert_eq!(
+            h.add_nodes(vec!["five"], [0]),
+            Err(errors::AddError::NoLocation(errors::NoHypergraph(vec![0])))
+        );
+        assert_eq!(h.node_count(), 2);
+    }
+
     #[test]
     fn add_edge() {
         let mut h = Hypergraph::<&str, &str>::new();
@@ -414,6 +499,41 @@ mod tests {
         assert_eq!(h.edge_value(&edge_id).unwrap(), &"two");
     }
 
+    #[test]
+    fn add_edge_in() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("node");
+        h.add_node("node");
+        h.add_edge([0], [1], "edge").unwrap();
+        h.add_node("node");
+        h.add_link([2], [5], "link").unwrap();
+        h.add_hypergraph("(sub)hypergraph");
+        h.add_node_in("node", [7]).unwrap();
+        h.add_node_in("node", [7]).unwrap();
+
+        let edge_id = h.add_edge_in([7, 0], [7, 1], "edge", [7]).unwrap();
+        assert_eq!(edge_id, vec![7, 2]);
+        assert_eq!(h.edge_value(&edge_id), Ok(&"edge"));
+        assert_eq!(Ok(vec![7, 3]), h.find_link_id([7, 0], [7, 2], None, [7]));
+        assert_eq!(Ok(vec![7, 4]), h.find_link_id([7, 2], [7, 1], None, [7]));
+        assert_eq!(h.add_link([2], [7, 0], "link"), Ok(vec![8]));
+        assert_eq!(h.add_link([2], [7], "link"), Ok(vec![9]));
+    }
+
+    #[test]
+    fn add_node_in() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("node");
+        h.add_hypergraph("(sub)hypergraph");
+        assert_eq!(h.add_node_in("node", [1]), Ok(vec![1, 0]));
+        assert_eq!(h.add_node_in("node", [1]), Ok(vec![1, 1]));
+        assert_eq!(h.node_value([1, 1]), Ok(&"node"));
+        assert_eq!(
+            h.add_node_in("node", [0]),
+            Err(errors::AddError::NoLocation(errors::NoHypergraph(vec![0])))
+        );
+    }
+
     #[test_case(
         {
             let mut h = Hypergraph::<_, ()>::new();
@@ -476,7 +596,7 @@ mod tests {
         {
             let mut h = Hypergraph::<(), (), _>::new();
             h.add_hypergraph("zero");
-            h.add_hypergraph_in("one", [0]);
+            h.add_hypergraph_in("one", [0]).unwrap();
             h.add_link([0, 0], [0], ())
         }, //
         errors::AddError::Unlinkable(errors::Unlinkable(vec![0, 0], vec![0])); //
@@ -503,6 +623,30 @@ mod tests {
         let link_id = h.add_link([0], [2], "three").unwrap();
         assert_eq!(h.link_value(link_id).unwrap(), &Some("three"));
     }
+
+    #[test]
+    fn ensure_link() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+
+        assert_eq!(h.ensure_link([0], [2], "five", []), Ok(vec![5]));
+        assert_eq!(h.ensure_link([0], [2], "five", []), Ok(vec![5]));
+        assert_eq!(h.links_between([0], [2], []), vec![vec![3], vec![5]]);
+        // The link created with the edge has no value
+        assert_eq!(h.ensure_link([0], [2], None, []), Ok(vec![3]));
+        assert_eq!(h.ensure_link([0], [2], "six", []), Ok(vec![6]));
+        assert_eq!(h.multiplicity([0], [2], []), 3);
+        assert_eq!(
+            h.ensure_link([0], [1], "seven", []),
+            Err(errors::AddError::Unlinkable(errors::Unlinkable(
+                vec![0],
+                vec![1]
+            )))
+        );
+    }
+
     #[test]
     fn add_node() {
         let mut h = Hypergraph::<_, u8>::new();
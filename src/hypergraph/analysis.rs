@@ -0,0 +1,1126 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    iter::Sum,
+};
+
+use crate::{
+    elements::{ElementType, ElementValue},
+    Direction, Hypergraph,
+};
+
+/// Precomputed reachability between the linkable elements of a hypergraph.
+///
+/// Created with [`Hypergraph::reachability`](struct.Hypergraph.html#method.reachability).
+/// It borrows the hypergraph, so that it can not change while the cache is alive.
+#[derive(Debug, Clone)]
+pub struct Reachability<'a, N, E, H, L, Ty> {
+    /// Hypergraph the cache refers to
+    hypergraph: &'a Hypergraph<N, E, H, L, Ty>,
+    /// Position of each linkable element in `closure`
+    index: HashMap<Vec<usize>, usize>,
+    /// Transitive closure, `closure[i][j]` is `true` if element `i` can reach element `j`
+    closure: Vec<Vec<bool>>,
+}
+
+impl<'a, N, E, H, L, Ty> Reachability<'a, N, E, H, L, Ty> {
+    /// Returns `true` if `target` can be reached from `source` following outgoing links.
+    ///
+    /// Coincides with [`path_exists`](struct.Hypergraph.html#method.path_exists) in the outgoing direction.
+    pub fn can_reach(&self, source: impl AsRef<[usize]>, target: impl AsRef<[usize]>) -> bool {
+        match (
+            self.index.get(source.as_ref()),
+            self.index.get(target.as_ref()),
+        ) {
+            (Some(&i), Some(&j)) => self.closure[i][j],
+            _ => false,
+        }
+    }
+
+    /// Returns the hypergraph the cache refers to.
+    pub fn hypergraph(&self) -> &'a Hypergraph<N, E, H, L, Ty> {
+        self.hypergraph
+    }
+}
+
+/// Linkable elements of a hypergraph and the links between them, indexed by position.
+///
+/// Used to match the structure of two hypergraphs in
+/// [`is_isomorphic_to_by`](struct.Hypergraph.html#method.is_isomorphic_to_by).
+struct LinkStructure {
+    /// Id of each linkable element, in the order of `ids`, so that hypergraphs precede their elements
+    ids: Vec<Vec<usize>>,
+    /// Kind of each element
+    kinds: Vec<ElementType>,
+    /// Position of the hypergraph containing each element, `None` for the main hypergraph
+    parents: Vec<Option<usize>>,
+    /// Number of incoming and outgoing links of each element
+    degrees: Vec<(usize, usize)>,
+    /// Number of links from one element to another
+    arcs: HashMap<(usize, usize), usize>,
+}
+
+impl LinkStructure {
+    fn new<N, E, H, L, Ty>(hypergraph: &Hypergraph<N, E, H, L, Ty>) -> Self {
+        let ids: Vec<Vec<usize>> = hypergraph
+            .ids()
+            .skip(1)
+            .filter(|id| hypergraph.contains_linkable(id))
+            .collect();
+        let index: HashMap<&[usize], usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.as_slice(), i))
+            .collect();
+        let kinds = ids
+            .iter()
+            .map(|id| hypergraph.element_type(id).unwrap()) // Never fails since id refers to an element
+            .collect();
+        let parents = ids
+            .iter()
+            .map(|id| index.get(&id[0..id.len() - 1]).copied())
+            .collect();
+        let mut degrees = vec![(0, 0); ids.len()];
+        let mut arcs = HashMap::new();
+        for (_, source, target, _) in hypergraph.iter_links() {
+            // Never fails since links connect linkable elements
+            let (source, target) = (index[source.as_slice()], index[target.as_slice()]);
+            degrees[source].1 += 1;
+            degrees[target].0 += 1;
+            *arcs.entry((source, target)).or_insert(0) += 1;
+        }
+        LinkStructure {
+            ids,
+            kinds,
+            parents,
+            degrees,
+            arcs,
+        }
+    }
+
+    /// Returns the number of links from element `source` to element `target`.
+    fn arcs(&self, source: usize, target: usize) -> usize {
+        self.arcs.get(&(source, target)).copied().unwrap_or(0)
+    }
+}
+
+/// # Analysis
+///
+/// Structural properties of the hypergraph.
+impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
+    /// Assigns to each linkable element, in all levels, the index of its weakly connected component.
+    ///
+    /// Two elements are in the same component if they are connected by links, regardless of their direction.
+    /// Components are indexed from zero, in increasing order of their smallest id. Computed with union-find.
+    pub fn component_labeling(&self) -> HashMap<Vec<usize>, usize> {
+        fn find(parents: &mut [usize], mut i: usize) -> usize {
+            while parents[i] != i {
+                parents[i] = parents[parents[i]];
+                i = parents[i];
+            }
+            i
+        }
+        let linkables: Vec<Vec<usize>> =
+            self.ids().filter(|id| self.contains_linkable(id)).collect();
+        let index: HashMap<&Vec<usize>, usize> = linkables
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id, i))
+            .collect();
+        let mut parents: Vec<usize> = (0..linkables.len()).collect();
+        for (_, source, target, _) in self.iter_links() {
+            let source_root = find(&mut parents, index[&source]);
+            let target_root = find(&mut parents, index[&target]);
+            // The smallest index is the representative
+            let (low, high) = (source_root.min(target_root), source_root.max(target_root));
+            parents[high] = low;
+        }
+        let mut labels: HashMap<usize, usize> = HashMap::new();
+        let mut labeling = HashMap::new();
+        for (i, id) in linkables.iter().enumerate() {
+            let root = find(&mut parents, i);
+            let next_label = labels.len();
+            let label = *labels.entry(root).or_insert(next_label);
+            labeling.insert(id.clone(), label);
+        }
+        labeling
+    }
+
+    /// Returns a cycle through `id`, following outgoing links, if there is one.
+    ///
+    /// The cycle is given as the path of linkable elements visited, starting and ending at `id`.
+    /// It is found by a depth-first search, so it is not necessarily the shortest one.
+    ///
+    /// Returns `None` if there is no such cycle, or if `id` is not a linkable element.
+    pub fn cycle_through(&self, id: impl AsRef<[usize]>) -> Option<Vec<Vec<usize>>> {
+        let id = id.as_ref();
+        if !self.contains_linkable(id) {
+            return None;
+        }
+        let mut previous: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+        let mut stack = vec![id.to_vec()];
+        while let Some(current_id) = stack.pop() {
+            let neighbors = self
+                .neighbors_directed(&current_id, Direction::Outgoing)
+                .unwrap(); // Never fails since current_id refers to a linkable element
+            for neighbor_id in neighbors {
+                if neighbor_id.as_slice() == id {
+                    let mut path = vec![neighbor_id.clone(), current_id.clone()];
+                    while let Some(previous_id) = previous.get(path.last().unwrap()) {
+                        // Never fails since path is not empty
+                        path.push(previous_id.clone());
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                if !previous.contains_key(neighbor_id) {
+                    previous.insert(neighbor_id.clone(), current_id.clone());
+                    stack.push(neighbor_id.clone());
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns all simple paths from `source` to `target`, following outgoing links, with at most `max_len` hops.
+    ///
+    /// Paths are given as the linkable elements visited, starting at `source` and ending at `target`,
+    /// and go through edges, so that each hop is a step to an outgoing neighbor.
+    /// No element is repeated within a path.
+    /// Paths are found by a backtracking depth-first search, so the number of paths can grow
+    /// exponentially with `max_len`: keep it small.
+    ///
+    /// Returns an empty vector if `source` or `target` is not a linkable element.
+    pub fn all_simple_paths(
+        &self,
+        source: impl AsRef<[usize]>,
+        target: impl AsRef<[usize]>,
+        max_len: usize,
+    ) -> Vec<Vec<Vec<usize>>> {
+        let source = source.as_ref();
+        let target = target.as_ref();
+        let mut paths = Vec::new();
+        if !self.contains_linkable(source) || !self.contains_linkable(target) {
+            return paths;
+        }
+        let mut path = vec![source.to_vec()];
+        self.collect_simple_paths(&mut path, target, max_len, &mut paths);
+        paths
+    }
+
+    /// Extends `path` in all possible ways towards `target`, pushing complete paths into `paths`.
+    fn collect_simple_paths(
+        &self,
+        path: &mut Vec<Vec<usize>>,
+        target: &[usize],
+        max_len: usize,
+        paths: &mut Vec<Vec<Vec<usize>>>,
+    ) {
+        let current_id = path.last().unwrap(); // Never fails since path is not empty
+        if current_id.as_slice() == target {
+            paths.push(path.clone());
+            return;
+        }
+        if path.len() > max_len {
+            return;
+        }
+        let neighbors: Vec<Vec<usize>> = self
+            .neighbors_directed(current_id, Direction::Outgoing)
+            .unwrap() // Never fails since current_id refers to a linkable element
+            .cloned()
+            .collect();
+        for neighbor_id in neighbors {
+            if !path.contains(&neighbor_id) {
+                path.push(neighbor_id);
+                self.collect_simple_paths(path, target, max_len, paths);
+                path.pop();
+            }
+        }
+    }
+
+    /// Returns the ids of all elements from which `id` can be reached, excluding `id` itself.
+    ///
+    /// The set is empty if `id` does not refer to a linkable element.
+    pub fn ancestors(&self, id: impl AsRef<[usize]>) -> HashSet<Vec<usize>> {
+        self.reachable(id, Direction::Incoming)
+    }
+
+    /// Returns the ids of all elements that can be reached from `id`, excluding `id` itself.
+    ///
+    /// The set is empty if `id` does not refer to a linkable element.
+    pub fn descendants(&self, id: impl AsRef<[usize]>) -> HashSet<Vec<usize>> {
+        self.reachable(id, Direction::Outgoing)
+    }
+
+    /// Returns the immediate dominator of every linkable element reachable from `root`, following outgoing links.
+    ///
+    /// An element `d` dominates `id` if every path from `root` to `id` goes through `d`.
+    /// The immediate dominator of `id` is its closest dominator other than itself.
+    /// `root` itself is not a key of the map. Edges are treated as any other linkable element,
+    /// so the immediate dominator of a node can be an edge. Runs the Cooper-Harvey-Kennedy algorithm.
+    ///
+    /// The map is empty if `root` does not refer to a linkable element.
+    pub fn dominators(&self, root: impl AsRef<[usize]>) -> HashMap<Vec<usize>, Vec<usize>> {
+        let root = root.as_ref();
+        if !self.contains_linkable(root) {
+            return HashMap::new();
+        }
+        // Postorder of the elements reachable from root
+        let mut postorder: Vec<Vec<usize>> = Vec::new();
+        let mut visited: HashSet<Vec<usize>> = HashSet::new();
+        visited.insert(root.to_vec());
+        let successors = |id: &Vec<usize>| -> Vec<Vec<usize>> {
+            self.neighbors_directed(id, Direction::Outgoing)
+                .unwrap() // Never fails since id refers to a linkable element
+                .cloned()
+                .collect()
+        };
+        let mut stack = vec![(root.to_vec(), successors(&root.to_vec()), 0)];
+        while let Some((id, children, next)) = stack.last_mut() {
+            match children.get(*next) {
+                Some(child) => {
+                    *next += 1;
+                    if visited.insert(child.clone()) {
+                        let child = child.clone();
+                        let grandchildren = successors(&child);
+                        stack.push((child, grandchildren, 0));
+                    }
+                }
+                None => {
+                    postorder.push(id.clone());
+                    stack.pop();
+                }
+            }
+        }
+        let index: HashMap<&Vec<usize>, usize> = postorder
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id, i))
+            .collect();
+        let root_index = postorder.len() - 1; // Never fails since root is in the postorder
+        let predecessors: Vec<Vec<usize>> = postorder
+            .iter()
+            .map(|id| {
+                self.neighbors_directed(id, Direction::Incoming)
+                    .unwrap() // Never fails since id refers to a linkable element
+                    .filter_map(|predecessor_id| index.get(predecessor_id).copied())
+                    .collect()
+            })
+            .collect();
+
+        let mut idom: Vec<Option<usize>> = vec![None; postorder.len()];
+        idom[root_index] = Some(root_index);
+        let intersect = |idom: &[Option<usize>], mut a: usize, mut b: usize| {
+            while a != b {
+                while a < b {
+                    a = idom[a].unwrap(); // Never fails since a was already processed
+                }
+                while b < a {
+                    b = idom[b].unwrap(); // Never fails since b was already processed
+                }
+            }
+            a
+        };
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for i in (0..root_index).rev() {
+                let mut new_idom = None;
+                for &p in &predecessors[i] {
+                    if idom[p].is_some() {
+                        new_idom = match new_idom {
+                            None => Some(p),
+                            Some(current) => Some(intersect(&idom, p, current)),
+                        };
+                    }
+                }
+                if new_idom.is_some() && idom[i] != new_idom {
+                    idom[i] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+
+        (0..root_index)
+            .map(|i| {
+                let dominator = idom[i].unwrap(); // Never fails since every element is reachable from root
+                (postorder[i].clone(), postorder[dominator].clone())
+            })
+            .collect()
+    }
+
+    /// Returns the length, in links, of a shortest cycle following outgoing links,
+    /// or `None` if there is no cycle.
+    ///
+    /// Since links always go to or from an edge, a cycle through `k` edges has length `2k`.
+    /// Runs a breadth-first search from each node and hypergraph, in all levels.
+    pub fn girth(&self) -> Option<usize> {
+        let mut girth: Option<usize> = None;
+        for source in self
+            .ids()
+            .filter(|id| self.contains_node(id) || self.contains_subhypergraph(id))
+        {
+            let mut distances: HashMap<Vec<usize>, usize> = HashMap::new();
+            distances.insert(source.clone(), 0);
+            let mut queue = VecDeque::from(vec![source.clone()]);
+            'search: while let Some(current_id) = queue.pop_front() {
+                let distance = distances[&current_id] + 1;
+                if girth.is_some_and(|best| distance >= best) {
+                    break;
+                }
+                let neighbors = self
+                    .neighbors_directed(&current_id, Direction::Outgoing)
+                    .unwrap(); // Never fails since current_id refers to a linkable element
+                for neighbor_id in neighbors {
+                    if neighbor_id == &source {
+                        girth = Some(distance);
+                        break 'search;
+                    }
+                    if !distances.contains_key(neighbor_id) {
+                        distances.insert(neighbor_id.clone(), distance);
+                        queue.push_back(neighbor_id.clone());
+                    }
+                }
+            }
+        }
+        girth
+    }
+
+    /// Returns `true` if `self` and `other` have the same structure, regardless of ids and values.
+    ///
+    /// Same as [`is_isomorphic_to_by`](#method.is_isomorphic_to_by), accepting any pair of values.
+    pub fn is_isomorphic_to<Ty2>(&self, other: &Hypergraph<N, E, H, L, Ty2>) -> bool {
+        self.is_isomorphic_to_by(other, |_, _| true)
+    }
+
+    /// Returns `true` if there is a bijection between the linkable elements of `self` and `other`
+    /// preserving their kind, the hypergraph they belong to and the number of links between each pair,
+    /// such that `compatible` holds for the values of every matched pair.
+    ///
+    /// Link values are ignored. The matching is found by a VF2-style backtracking search,
+    /// which takes exponential time in the worst case.
+    pub fn is_isomorphic_to_by<Ty2, F>(
+        &self,
+        other: &Hypergraph<N, E, H, L, Ty2>,
+        mut compatible: F,
+    ) -> bool
+    where
+        F: FnMut(ElementValue<&N, &E, &H, &L>, ElementValue<&N, &E, &H, &L>) -> bool,
+    {
+        let one = LinkStructure::new(self);
+        let two = LinkStructure::new(other);
+        if one.ids.len() != two.ids.len() || one.arcs.len() != two.arcs.len() {
+            return false;
+        }
+        let mut compatible = |i: usize, j: usize| {
+            compatible(
+                self.element_value(&one.ids[i]).unwrap(), // Never fails since one.ids refers to elements
+                other.element_value(&two.ids[j]).unwrap(), // Never fails since two.ids refers to elements
+            )
+        };
+        let mut mapping = Vec::with_capacity(one.ids.len());
+        let mut used = vec![false; two.ids.len()];
+        match_structures(&one, &two, &mut mapping, &mut used, &mut compatible)
+    }
+
+    /// Returns the ids of all linkable elements without links, in all levels.
+    pub fn isolated_elements(&self) -> Vec<Vec<usize>> {
+        self.ids()
+            .filter(|id| matches!(self.links_of(id), Ok(links) if links.is_empty()))
+            .collect()
+    }
+
+    /// Returns `true` if `id` refers to a linkable element without outgoing links.
+    pub fn is_leaf(&self, id: impl AsRef<[usize]>) -> bool {
+        self.out_degree(id) == Ok(0)
+    }
+
+    /// Returns `true` if `id` refers to a linkable element without incoming links.
+    pub fn is_root(&self, id: impl AsRef<[usize]>) -> bool {
+        self.in_degree(id) == Ok(0)
+    }
+
+    /// Returns the ids of all linkable elements without outgoing links, in all levels.
+    ///
+    /// Edges always have outgoing links, so they are never leaves.
+    pub fn leaves(&self) -> Vec<Vec<usize>> {
+        self.ids().filter(|id| self.is_leaf(id)).collect()
+    }
+
+    /// Returns the ids of all linkable elements whose distance from `id`, following links in `direction`, is exactly `k`.
+    ///
+    /// The distance is the number of links in a shortest path.
+    /// Elements are returned in breadth-first order.
+    pub fn neighbors_at_distance(
+        &self,
+        id: impl AsRef<[usize]>,
+        k: usize,
+        direction: Direction,
+    ) -> Vec<Vec<usize>> {
+        let mut layers = self.layers(id, k, direction);
+        if layers.len() == k + 1 {
+            layers.pop().unwrap() // Never fails since there are k + 1 layers
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns `true` if `target` can be reached from `source` following links in `direction`.
+    ///
+    /// Paths go through edges, so that, for example, two nodes connected by an edge are connected by a path.
+    /// Every linkable element is reachable from itself.
+    ///
+    /// The search stops as soon as `target` is found.
+    pub fn path_exists(
+        &self,
+        source: impl AsRef<[usize]>,
+        target: impl AsRef<[usize]>,
+        direction: Direction,
+    ) -> bool {
+        let source = source.as_ref();
+        let target = target.as_ref();
+        if !self.contains_linkable(source) || !self.contains_linkable(target) {
+            return false;
+        }
+        if source == target {
+            return true;
+        }
+        let mut visited: HashSet<&[usize]> = HashSet::new();
+        visited.insert(source);
+        let mut queue = VecDeque::new();
+        queue.push_back(source.to_vec());
+        while let Some(id) = queue.pop_front() {
+            let neighbors = self.neighbors_directed(&id, direction).unwrap(); // Never fails since id refers to a linkable element
+            for neighbor_id in neighbors {
+                if neighbor_id.as_slice() == target {
+                    return true;
+                }
+                if visited.insert(neighbor_id) {
+                    queue.push_back(neighbor_id.clone());
+                }
+            }
+        }
+        false
+    }
+
+    /// Precomputes the transitive closure of `self`, following outgoing links,
+    /// so that reachability queries take constant time.
+    pub fn reachability(&self) -> Reachability<'_, N, E, H, L, Ty> {
+        let index: HashMap<Vec<usize>, usize> = self
+            .ids()
+            .filter(|id| self.contains_linkable(id))
+            .enumerate()
+            .map(|(i, id)| (id, i))
+            .collect();
+        let mut closure = vec![vec![false; index.len()]; index.len()];
+        for (id, &i) in &index {
+            closure[i][i] = true;
+            for descendant_id in self.descendants(id) {
+                closure[i][index[&descendant_id]] = true;
+            }
+        }
+        Reachability {
+            hypergraph: self,
+            index,
+            closure,
+        }
+    }
+
+    /// Returns the ids of all linkable elements without incoming links, in all levels.
+    ///
+    /// Edges always have incoming links, so they are never roots.
+    pub fn roots(&self) -> Vec<Vec<usize>> {
+        self.ids().filter(|id| self.is_root(id)).collect()
+    }
+
+    /// Returns the cost and the ids of a cheapest path from `source` to `target`, following outgoing links.
+    ///
+    /// The cost of each link is given by `weight` applied to its value, and the cost of a path is the sum
+    /// of the cost of its links. The path contains all linkable elements visited, from `source` to `target`
+    /// both included. Runs Dijkstra's algorithm.
+    ///
+    /// Returns `None` if `target` can not be reached from `source`, or if any of them is not a linkable element.
+    /// Paths whose cost overflows `u64` are not considered.
+    pub fn shortest_path_weighted<W>(
+        &self,
+        source: impl AsRef<[usize]>,
+        target: impl AsRef<[usize]>,
+        weight: W,
+    ) -> Option<(u64, Vec<Vec<usize>>)>
+    where
+        W: Fn(&Option<L>) -> u64,
+    {
+        let source = source.as_ref();
+        let target = target.as_ref();
+        if !self.contains_linkable(source) || !self.contains_linkable(target) {
+            return None;
+        }
+        let mut costs: HashMap<Vec<usize>, u64> = HashMap::new();
+        let mut previous: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+        costs.insert(source.to_vec(), 0);
+        heap.push(Reverse((0, source.to_vec())));
+        while let Some(Reverse((cost, id))) = heap.pop() {
+            if id == target {
+                let mut path = vec![id];
+                while let Some(previous_id) = previous.get(path.last().unwrap()) {
+                    // Never fails since path is not empty
+                    path.push(previous_id.clone());
+                }
+                path.reverse();
+                return Some((cost, path));
+            }
+            if costs.get(&id).is_some_and(|&best| cost > best) {
+                continue;
+            }
+            let neighbors = self.neighbors_with_links(&id, Direction::Outgoing).unwrap(); // Never fails since id refers to a linkable element
+            for (link_id, neighbor_id, _) in neighbors {
+                // Never fails since link exists
+                let link_value = self.link_value(link_id).unwrap();
+                // Paths whose cost does not fit in u64 are ignored
+                let next_cost = match cost.checked_add(weight(link_value)) {
+                    Some(next_cost) => next_cost,
+                    None => continue,
+                };
+                #[allow(clippy::unnecessary_map_or)]
+                if costs
+                    .get(neighbor_id)
+                    .map_or(true, |&best| next_cost < best)
+                {
+                    costs.insert(neighbor_id.clone(), next_cost);
+                    previous.insert(neighbor_id.clone(), id.clone());
+                    heap.push(Reverse((next_cost, neighbor_id.clone())));
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the sum of `f` applied to the value of every edge, in all levels.
+    pub fn sum_edge_values<T, F>(&self, f: F) -> T
+    where
+        T: Sum,
+        F: Fn(&E) -> T,
+    {
+        self.ids()
+            .filter_map(|id| self.try_edge_value(id))
+            .map(f)
+            .sum()
+    }
+
+    /// Returns the sum of `f` applied to the value of every link, in all levels.
+    pub fn sum_link_values<T, F>(&self, f: F) -> T
+    where
+        T: Sum,
+        F: Fn(&Option<L>) -> T,
+    {
+        self.iter_links().map(|(_, _, _, value)| f(value)).sum()
+    }
+
+    /// Returns the sum of `f` applied to the value of every node, in all levels.
+    pub fn sum_node_values<T, F>(&self, f: F) -> T
+    where
+        T: Sum,
+        F: Fn(&N) -> T,
+    {
+        self.ids()
+            .filter_map(|id| self.try_node_value(id))
+            .map(f)
+            .sum()
+    }
+
+    /// Returns the ids of all linkable elements whose distance from `id`, following links in `direction`,
+    /// is between one and `k`.
+    ///
+    /// The distance is the number of links in a shortest path.
+    /// Elements are returned in breadth-first order.
+    pub fn within_distance(
+        &self,
+        id: impl AsRef<[usize]>,
+        k: usize,
+        direction: Direction,
+    ) -> Vec<Vec<usize>> {
+        self.layers(id, k, direction)
+            .into_iter()
+            .skip(1)
+            .flatten()
+            .collect()
+    }
+
+    /// Layered breadth-first search from `id` following links in `direction`, up to distance `k`.
+    ///
+    /// The `i`-th layer contains the elements at distance `i`. Layers after the last non-empty one are omitted.
+    /// The output is empty if `id` does not refer to a linkable element.
+    fn layers(
+        &self,
+        id: impl AsRef<[usize]>,
+        k: usize,
+        direction: Direction,
+    ) -> Vec<Vec<Vec<usize>>> {
+        let id = id.as_ref();
+        if !self.contains_linkable(id) {
+            return Vec::new();
+        }
+        let mut visited = HashSet::new();
+        visited.insert(id.to_vec());
+        let mut layers = vec![vec![id.to_vec()]];
+        while layers.len() <= k {
+            let mut next_layer = Vec::new();
+            let current_layer = layers.last().unwrap(); // Never fails since there is at least one layer
+            for current_id in current_layer {
+                let neighbors = self.neighbors_directed(current_id, direction).unwrap(); // Never fails since current_id refers to a linkable element
+                for neighbor_id in neighbors {
+                    if visited.insert(neighbor_id.clone()) {
+                        next_layer.push(neighbor_id.clone());
+                    }
+                }
+            }
+            if next_layer.is_empty() {
+                break;
+            }
+            layers.push(next_layer);
+        }
+        layers
+    }
+
+    /// Breadth-first search from `id` following links in `direction`.
+    ///
+    /// The output never contains `id`.
+    fn reachable(&self, id: impl AsRef<[usize]>, direction: Direction) -> HashSet<Vec<usize>> {
+        let id = id.as_ref();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(id.to_vec());
+        while let Some(current_id) = queue.pop_front() {
+            // An invalid id has no neighbors
+            for neighbor_id in self
+                .neighbors_directed(&current_id, direction)
+                .into_iter()
+                .flatten()
+            {
+                if visited.insert(neighbor_id.clone()) {
+                    queue.push_back(neighbor_id.clone());
+                }
+            }
+        }
+        visited.remove(id);
+        visited
+    }
+}
+
+/// Extends `mapping`, from the first elements of `one` to elements of `two`, to all elements of `one`.
+///
+/// Returns `false` if there is no such extension, leaving `mapping` and `used` unchanged.
+fn match_structures<F>(
+    one: &LinkStructure,
+    two: &LinkStructure,
+    mapping: &mut Vec<usize>,
+    used: &mut Vec<bool>,
+    compatible: &mut F,
+) -> bool
+where
+    F: FnMut(usize, usize) -> bool,
+{
+    let i = mapping.len();
+    if i == one.ids.len() {
+        return true;
+    }
+    for j in 0..two.ids.len() {
+        // Parents are already mapped since hypergraphs precede their elements
+        let feasible = !used[j]
+            && one.kinds[i] == two.kinds[j]
+            && one.degrees[i] == two.degrees[j]
+            && one.parents[i].map(|parent| mapping[parent]) == two.parents[j]
+            && one.arcs(i, i) == two.arcs(j, j)
+            && (0..i).all(|k| {
+                one.arcs(i, k) == two.arcs(j, mapping[k])
+                    && one.arcs(k, i) == two.arcs(mapping[k], j)
+            })
+            && compatible(i, j);
+        if feasible {
+            mapping.push(j);
+            used[j] = true;
+            if match_structures(one, two, mapping, used, compatible) {
+                return true;
+            }
+            mapping.pop();
+            used[j] = false;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ancestors() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+
+        let expected: HashSet<Vec<usize>> = vec![vec![0], vec![2]].into_iter().collect();
+        assert_eq!(h.ancestors([1]), expected);
+        assert!(h.ancestors([0]).is_empty());
+        assert!(h.ancestors([3]).is_empty());
+    }
+
+    #[test]
+    fn isolated_elements() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+        assert_eq!(h.isolated_elements(), vec![vec![5]]);
+
+        h.add_hypergraph("six");
+        h.add_node_in("seven", [6]).unwrap();
+        assert_eq!(h.isolated_elements(), vec![vec![5], vec![6], vec![6, 0]]);
+    }
+
+    #[test]
+    fn component_labeling() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_node("two");
+        h.add_node("three");
+        h.add_edge([0], [1], "four").unwrap();
+        h.add_edge([3], [2], "seven").unwrap();
+
+        let labeling = h.component_labeling();
+        assert_eq!(labeling.len(), 6);
+        assert_eq!(labeling[&vec![0]], 0);
+        assert_eq!(labeling[&vec![1]], 0);
+        assert_eq!(labeling[&vec![4]], 0);
+        assert_eq!(labeling[&vec![2]], 1);
+        assert_eq!(labeling[&vec![3]], 1);
+        assert_eq!(labeling[&vec![7]], 1);
+    }
+
+    #[test]
+    fn all_simple_paths() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_node("two");
+        h.add_edge([0], [1], "three").unwrap();
+        h.add_edge([1], [2], "six").unwrap();
+        h.add_edge([0], [2], "nine").unwrap();
+        h.add_edge([2], [0], "twelve").unwrap();
+
+        let paths = h.all_simple_paths([0], [2], 4);
+        assert_eq!(
+            paths,
+            vec![
+                vec![vec![0], vec![3], vec![1], vec![6], vec![2]],
+                vec![vec![0], vec![9], vec![2]],
+            ]
+        );
+        assert_eq!(
+            h.all_simple_paths([0], [2], 2),
+            vec![vec![vec![0], vec![9], vec![2]]]
+        );
+        assert_eq!(h.all_simple_paths([0], [0], 4), vec![vec![vec![0]]]);
+        assert!(h.all_simple_paths([0], [15], 4).is_empty());
+    }
+
+    #[test]
+    fn is_isomorphic_to() {
+        let mut chain = Hypergraph::<_, _>::new();
+        chain.add_node("a");
+        chain.add_node("b");
+        chain.add_node("c");
+        chain.add_edge([0], [1], "ab").unwrap();
+        chain.add_edge([1], [2], "bc").unwrap();
+
+        // Same chain, with elements added in a different order
+        let mut relabeled = Hypergraph::<_, _>::new();
+        relabeled.add_node("c");
+        relabeled.add_node("b");
+        relabeled.add_edge([1], [0], "bc").unwrap();
+        relabeled.add_node("a");
+        relabeled.add_edge([5], [1], "ab").unwrap();
+        assert!(chain.is_isomorphic_to(&relabeled));
+        assert!(relabeled.is_isomorphic_to(&chain));
+        assert!(chain.is_isomorphic_to_by(&relabeled, |one, other| one == other));
+
+        // Same topology, different values
+        let mut renamed = relabeled.clone();
+        renamed.set_node_value([0], "d").unwrap();
+        assert!(chain.is_isomorphic_to(&renamed));
+        assert!(!chain.is_isomorphic_to_by(&renamed, |one, other| one == other));
+
+        // Different topology: both edges leave the middle node
+        let mut star = Hypergraph::<_, _>::new();
+        star.add_node("a");
+        star.add_node("b");
+        star.add_node("c");
+        star.add_edge([1], [0], "ab").unwrap();
+        star.add_edge([1], [2], "bc").unwrap();
+        assert!(!chain.is_isomorphic_to(&star));
+
+        // Nesting is part of the structure
+        let mut nested = Hypergraph::<_, ()>::new();
+        nested.add_hypergraph(());
+        let mut flat = nested.clone();
+        nested.add_node_in("a", [0]).unwrap();
+        flat.add_node("a");
+        assert!(!nested.is_isomorphic_to(&flat));
+        assert!(nested.is_isomorphic_to(&nested.clone()));
+    }
+
+    #[test]
+    fn cycle_through() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_node("two");
+        h.add_edge([0], [1], "three").unwrap();
+        h.add_edge([1], [2], "six").unwrap();
+        assert_eq!(h.cycle_through([0]), None);
+
+        h.add_edge([2], [0], "nine").unwrap();
+        let cycle = h.cycle_through([0]).unwrap();
+        assert_eq!(
+            cycle,
+            vec![
+                vec![0],
+                vec![3],
+                vec![1],
+                vec![6],
+                vec![2],
+                vec![9],
+                vec![0]
+            ]
+        );
+        assert_eq!(h.cycle_through([4]), None);
+    }
+
+    #[test]
+    fn descendants() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_edge([1], [0], "five").unwrap();
+
+        let expected: HashSet<Vec<usize>> = vec![vec![1], vec![2], vec![5]].into_iter().collect();
+        assert_eq!(h.descendants([0]), expected);
+    }
+
+    #[test]
+    fn dominators() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("split");
+        h.add_node("left");
+        h.add_node("right");
+        h.add_node("merge");
+        h.add_edge([0], [1], "four").unwrap();
+        h.add_edge([0], [2], "seven").unwrap();
+        h.add_edge([1], [3], "ten").unwrap();
+        h.add_edge([2], [3], "thirteen").unwrap();
+
+        let dominators = h.dominators([0]);
+        assert_eq!(dominators.get(&vec![3]), Some(&vec![0]));
+        assert_eq!(dominators.get(&vec![1]), Some(&vec![4]));
+        assert_eq!(dominators.get(&vec![10]), Some(&vec![1]));
+        assert_eq!(dominators.get(&vec![0]), None);
+        assert_eq!(dominators.len(), 7);
+        assert!(h.dominators([5]).is_empty());
+    }
+
+    #[test]
+    fn girth() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_node("two");
+        h.add_edge([0], [1], "three").unwrap();
+        h.add_edge([0], [2], "six").unwrap();
+        assert_eq!(h.girth(), None);
+
+        h.add_edge([1], [2], "nine").unwrap();
+        assert_eq!(h.girth(), None);
+        h.add_edge([2], [0], "twelve").unwrap();
+        assert_eq!(h.girth(), Some(4));
+
+        let mut triangle = Hypergraph::<_, _>::new();
+        triangle.add_node("zero");
+        triangle.add_node("one");
+        triangle.add_node("two");
+        triangle.add_edge([0], [1], "three").unwrap();
+        triangle.add_edge([1], [2], "six").unwrap();
+        triangle.add_edge([2], [0], "nine").unwrap();
+        assert_eq!(triangle.girth(), Some(6));
+    }
+
+    #[test]
+    fn leaves() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        assert_eq!(h.leaves(), vec![vec![1]]);
+        assert!(h.is_leaf([1]));
+        assert!(!h.is_leaf([2]));
+        assert!(!h.is_leaf([3]));
+    }
+
+    #[test]
+    fn neighbors_at_distance() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+
+        assert_eq!(
+            h.neighbors_at_distance([0], 0, Direction::Outgoing),
+            vec![vec![0]]
+        );
+        assert_eq!(
+            h.neighbors_at_distance([0], 1, Direction::Outgoing),
+            vec![vec![2]]
+        );
+        assert_eq!(
+            h.neighbors_at_distance([0], 2, Direction::Outgoing),
+            vec![vec![1]]
+        );
+        assert!(h
+            .neighbors_at_distance([0], 3, Direction::Outgoing)
+            .is_empty());
+        assert_eq!(
+            h.neighbors_at_distance([1], 2, Direction::Incoming),
+            vec![vec![0]]
+        );
+        assert!(h
+            .neighbors_at_distance([3], 0, Direction::Outgoing)
+            .is_empty());
+    }
+
+    #[test]
+    fn path_exists() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+
+        assert!(h.path_exists([0], [1], Direction::Outgoing));
+        assert!(!h.path_exists([0], [1], Direction::Incoming));
+        assert!(h.path_exists([1], [0], Direction::Incoming));
+        assert!(h.path_exists([0], [2], Direction::Outgoing));
+        assert!(!h.path_exists([0], [5], Direction::Outgoing));
+        assert!(!h.path_exists([0], [3], Direction::Outgoing));
+    }
+
+    #[test]
+    fn reachability() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+        h.add_hypergraph("six");
+        h.add_node_in("seven", [6]).unwrap();
+        h.add_edge([1], [6, 0], "eight").unwrap();
+
+        let reach = h.reachability();
+        let ids: Vec<_> = h.ids().chain(vec![vec![9]]).collect();
+        for source in &ids {
+            for target in &ids {
+                assert_eq!(
+                    reach.can_reach(source, target),
+                    h.path_exists(source, target, Direction::Outgoing)
+                );
+            }
+        }
+        assert!(reach.can_reach([0], [6, 0]));
+        assert!(!reach.can_reach([6, 0], [0]));
+    }
+
+    #[test]
+    fn roots() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        assert_eq!(h.roots(), vec![vec![0]]);
+        assert!(h.is_root([0]));
+        assert!(!h.is_root([2]));
+        assert!(!h.is_root([]));
+    }
+
+    #[test]
+    fn shortest_path_weighted() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], 1).unwrap();
+        h.set_link_value([3], Some(10)).unwrap();
+        h.set_link_value([4], Some(1)).unwrap();
+        let weight = |value: &Option<u64>| value.unwrap_or(100);
+
+        assert_eq!(
+            h.shortest_path_weighted([0], [1], weight),
+            Some((2, vec![vec![0], vec![2], vec![1]]))
+        );
+        h.set_link_value([5], Some(20)).unwrap();
+        assert_eq!(
+            h.shortest_path_weighted([0], [1], weight),
+            Some((11, vec![vec![0], vec![2], vec![1]]))
+        );
+        assert_eq!(
+            h.shortest_path_weighted([0], [0], weight),
+            Some((0, vec![vec![0]]))
+        );
+        assert_eq!(h.shortest_path_weighted([1], [0], weight), None);
+        assert_eq!(h.shortest_path_weighted([0], [3], weight), None);
+
+        // Overflow
+        h.set_link_value([3], Some(u64::MAX)).unwrap();
+        h.set_link_value([5], Some(u64::MAX)).unwrap();
+        assert_eq!(
+            h.shortest_path_weighted([0], [2], weight),
+            Some((u64::MAX, vec![vec![0], vec![2]]))
+        );
+        assert_eq!(h.shortest_path_weighted([0], [1], weight), None);
+    }
+
+    #[test]
+    fn sum_values() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node(1);
+        h.add_node(2);
+        h.add_edge([0], [1], 3).unwrap();
+        h.set_link_value([3], 10).unwrap();
+        h.add_link([0], [2], 20).unwrap();
+
+        assert_eq!(h.sum_link_values(|value| value.unwrap_or(0)), 30);
+        assert_eq!(h.sum_link_values(|value| value.is_none() as usize), 1);
+        assert_eq!(h.sum_node_values(|value| *value), 3);
+        assert_eq!(h.sum_edge_values(|value| f64::from(*value)), 3.0);
+    }
+
+    #[test]
+    fn within_distance() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+
+        assert!(h.within_distance([0], 0, Direction::Outgoing).is_empty());
+        assert_eq!(
+            h.within_distance([0], 2, Direction::Outgoing),
+            vec![vec![2], vec![1]]
+        );
+        assert_eq!(
+            h.within_distance([0], 5, Direction::Outgoing),
+            vec![vec![2], vec![1]]
+        );
+    }
+}
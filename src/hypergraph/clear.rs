@@ -17,6 +17,21 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
         self
     }
 
+    /// Clears the hypergraph, removing everything inside, and restarts the id space.
+    ///
+    /// After a reset, new elements are given ids starting from `[0]`, as in a new hypergraph.
+    ///
+    /// # Remarks
+    ///
+    /// Ids obtained before the reset may be given to new elements,
+    /// so this should be used only when no such id is kept elsewhere.
+    /// This method has no effect on the allocated capacity.
+    pub fn reset(&mut self) -> &mut Self {
+        self.clear();
+        self.next_id = 0;
+        self
+    }
+
     /// Clears the edges of the top level. Nested hypergraphs remain unchanged.
     ///
     /// # Remarks
@@ -73,3 +88,23 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset() {
+        let mut h = Hypergraph::<&str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+
+        h.clear();
+        assert_eq!(h.add_node("six"), vec![6]);
+        h.reset();
+        assert_eq!(h.element_count(), 1);
+        assert_eq!(h.add_node("zero"), vec![0]);
+    }
+}
@@ -0,0 +1,218 @@
+use indexmap::IndexMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::checked_next_id;
+use crate::{traits::HypergraphClass, Direction, Hypergraph, LinkEntry, Main, Sub};
+
+/// Serialization format of a hypergraph without neighbor lists, which can be rebuilt from the links.
+#[derive(Serialize, Deserialize)]
+struct CompactHypergraph<N, E, H, L> {
+    value: Option<H>,
+    nodes: IndexMap<usize, N>,
+    edges: IndexMap<usize, E>,
+    links: IndexMap<usize, LinkEntry<L>>,
+    hypergraphs: IndexMap<usize, CompactHypergraph<N, E, H, L>>,
+    next_id: usize,
+}
+
+impl<'a, N, E, H, L> CompactHypergraph<&'a N, &'a E, &'a H, &'a L> {
+    /// Borrows the values of `hypergraph`, dropping its neighbor lists.
+    fn new<Ty>(hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> Self {
+        CompactHypergraph {
+            value: hypergraph.value.as_ref(),
+            nodes: hypergraph
+                .nodes
+                .iter()
+                .map(|(local_id, (value, _))| (*local_id, value))
+                .collect(),
+            edges: hypergraph
+                .edges
+                .iter()
+                .map(|(local_id, (value, _))| (*local_id, value))
+                .collect(),
+            links: hypergraph
+                .links
+                .iter()
+                .map(|(local_id, (value, source, target))| {
+                    (*local_id, (value.as_ref(), source.clone(), target.clone()))
+                })
+                .collect(),
+            hypergraphs: hypergraph
+                .hypergraphs
+                .iter()
+                .map(|(local_id, (subhypergraph, _))| (*local_id, Self::new(subhypergraph)))
+                .collect(),
+            next_id: hypergraph.next_id,
+        }
+    }
+}
+
+impl<N, E, H, L> CompactHypergraph<N, E, H, L> {
+    /// Moves all values into a hypergraph with empty neighbor lists.
+    fn into_hypergraph<Ty: HypergraphClass>(self) -> Hypergraph<N, E, H, L, Ty> {
+        let mut hypergraph = Hypergraph::new();
+        hypergraph.value = self.value;
+        hypergraph.nodes = self
+            .nodes
+            .into_iter()
+            .map(|(local_id, value)| (local_id, (value, Vec::new())))
+            .collect();
+        hypergraph.edges = self
+            .edges
+            .into_iter()
+            .map(|(local_id, value)| (local_id, (value, Vec::new())))
+            .collect();
+        hypergraph.links = self.links;
+        hypergraph.hypergraphs = self
+            .hypergraphs
+            .into_iter()
+            .map(|(local_id, compact)| (local_id, (compact.into_hypergraph::<Sub>(), Vec::new())))
+            .collect();
+        hypergraph.next_id = checked_next_id(
+            self.next_id,
+            hypergraph
+                .edges
+                .keys()
+                .chain(hypergraph.hypergraphs.keys())
+                .chain(hypergraph.links.keys())
+                .chain(hypergraph.nodes.keys()),
+        );
+        hypergraph
+    }
+}
+
+/// # Compact serialization
+///
+/// JSON without neighbor lists. Requires the `serde_json` feature.
+impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty>
+where
+    N: Serialize,
+    E: Serialize,
+    H: Serialize,
+    L: Serialize,
+{
+    /// Serializes `self` into JSON, skipping the neighbor lists of linkable elements.
+    ///
+    /// Neighbor lists are fully determined by the links, so this roughly halves the output size.
+    /// Use [`from_json_compact`](#method.from_json_compact) to read it back.
+    ///
+    /// # Errors
+    ///
+    /// If the serialization of any value fails.
+    pub fn to_json_compact(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&CompactHypergraph::new(self))
+    }
+}
+
+impl<N, E, H, L> Hypergraph<N, E, H, L, Main>
+where
+    N: DeserializeOwned,
+    E: DeserializeOwned,
+    H: DeserializeOwned,
+    L: DeserializeOwned,
+{
+    /// Deserializes a hypergraph from the output of [`to_json_compact`](#method.to_json_compact).
+    ///
+    /// Neighbor lists are rebuilt by scanning all links, in the order of [`ids`](#method.ids).
+    /// Next ids which are not bigger than all local ids are recomputed,
+    /// as when deserializing with [`serde`](https://docs.rs/serde).
+    ///
+    /// # Errors
+    ///
+    /// If `json` is not valid, or if some link could not have been added with
+    /// [`add_link_in`](#method.add_link_in), or if some edge has less than two links.
+    pub fn from_json_compact(json: &str) -> Result<Self, serde_json::Error> {
+        let compact: CompactHypergraph<N, E, H, L> = serde_json::from_str(json)?;
+        let mut hypergraph: Self = compact.into_hypergraph();
+        let links: Vec<(Vec<usize>, Vec<usize>, Vec<usize>)> = hypergraph
+            .iter_links()
+            .map(|(id, source, target, _)| (id, source, target))
+            .collect();
+        for (id, source, target) in links {
+            // Links connect an edge with a node or hypergraph
+            if hypergraph.contains_edge(&source) == hypergraph.contains_edge(&target) {
+                return Err(serde::de::Error::custom(format!(
+                    "the link {:?} does not connect an edge with a node or hypergraph",
+                    id
+                )));
+            }
+            // Links are located in a hypergraph containing both endpoints
+            let location = &id[..id.len() - 1];
+            if !source.starts_with(location) || !target.starts_with(location) {
+                return Err(serde::de::Error::custom(format!(
+                    "the link {:?} is not coherent with its endpoints",
+                    id
+                )));
+            }
+            for (endpoint, direction) in
+                [(source, Direction::Outgoing), (target, Direction::Incoming)]
+            {
+                match hypergraph.links_of_mut(&endpoint) {
+                    Ok(endpoint_links) => endpoint_links.push((id.clone(), direction)),
+                    Err(_) => {
+                        return Err(serde::de::Error::custom(format!(
+                            "the endpoint {:?} of link {:?} is not a linkable element",
+                            endpoint, id
+                        )))
+                    }
+                }
+            }
+        }
+        match hypergraph.validate() {
+            Ok(()) => Ok(hypergraph),
+            Err(errors) => Err(serde::de::Error::custom(format!(
+                "invalid hypergraph: {:?}",
+                errors
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hypergraph::tests::{assert_same_elements, example};
+
+    #[test]
+    fn json_compact_round_trip() {
+        let h = example();
+
+        let compact = h.to_json_compact().unwrap();
+        let full = serde_json::to_string(&h).unwrap();
+        assert!(compact.len() < full.len());
+
+        let copy =
+            Hypergraph::<String, String, String, String>::from_json_compact(&compact).unwrap();
+        assert_same_elements(&copy, &h);
+        assert_eq!(copy.next_local_id(), h.next_local_id());
+        assert_eq!(copy.validate(), Ok(()));
+    }
+
+    #[test]
+    fn json_compact_invalid_link() {
+        let json = r#"{"value":null,"nodes":{"0":"zero"},"edges":{},"links":{"1":[null,[0],[7]]},"hypergraphs":{},"next_id":2}"#;
+        assert!(Hypergraph::<String, (), (), ()>::from_json_compact(json).is_err());
+
+        // Node -> Node
+        let json = r#"{"value":null,"nodes":{"0":"zero","1":"one"},"edges":{},"links":{"2":[null,[0],[1]]},"hypergraphs":{},"next_id":3}"#;
+        assert!(Hypergraph::<String, (), (), ()>::from_json_compact(json).is_err());
+
+        // Edge with a single link
+        let json = r#"{"value":null,"nodes":{"0":"zero"},"edges":{"1":null},"links":{"2":[null,[0],[1]]},"hypergraphs":{},"next_id":3}"#;
+        assert!(Hypergraph::<String, (), (), ()>::from_json_compact(json).is_err());
+    }
+
+    #[test]
+    fn json_compact_next_id_drift() {
+        let mut h = example();
+        h.next_id = 1;
+        h.subhypergraph_mut([7]).unwrap().next_id = 0;
+        let compact = h.to_json_compact().unwrap();
+
+        let copy =
+            Hypergraph::<String, String, String, String>::from_json_compact(&compact).unwrap();
+        assert_eq!(copy.next_local_id(), 10);
+        assert_eq!(copy.subhypergraph([7]).unwrap().next_local_id(), 5);
+        assert_eq!(copy.validate(), Ok(()));
+    }
+}
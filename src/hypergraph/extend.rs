@@ -1,6 +1,6 @@
-use core::mem;
+use core::{iter::FromIterator, mem};
 
-use crate::{errors, Hypergraph, Main, Sub};
+use crate::{elements::ElementExt, errors, Hypergraph, Main, Sub};
 
 /// # Add
 ///
@@ -22,11 +22,156 @@ impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
     {
         let location = location.as_ref();
         let mut other: Hypergraph<N, E, H, L, Sub> = other.clone().into_sub();
-        let new_hypergraph_id = self.add_hypergraph_in(other.value().clone(), &location)?;
+        let new_hypergraph_id = self.add_hypergraph_in(other.value().clone(), location)?;
         let subhypergraph = self.subhypergraph_mut(&new_hypergraph_id).unwrap(); // Never fails since new_hypergraph_id refers to a hypergraph
         mem::swap(subhypergraph, &mut other);
         subhypergraph.preappend_id(location);
 
         Ok(new_hypergraph_id)
     }
+
+    /// Creates a hypergraph from a list of edges between node indices, all in the top level.
+    ///
+    /// Nodes are created first, with values `N::default()`, so that the node with index `i` has id `[i]`.
+    /// Then, one edge is added per item of `iter`, in order.
+    pub fn from_edges<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, E)>,
+        N: Default,
+    {
+        let edges: Vec<_> = iter.into_iter().collect();
+        let node_count = edges
+            .iter()
+            .map(|(source, target, _)| source.max(target) + 1)
+            .max()
+            .unwrap_or(0);
+        let mut hypergraph = Self::new();
+        hypergraph
+            .add_nodes((0..node_count).map(|_| N::default()), [])
+            .unwrap(); // Never fails since the top level is a hypergraph
+        for (source, target, value) in edges {
+            hypergraph.add_edge([source], [target], value).unwrap(); // Never fails since source and target are nodes
+        }
+        hypergraph
+    }
+
+    /// Adds all elements in `iter` to the top level, in order, returning their ids.
+    ///
+    /// Ids of connections in `iter` can refer to elements added earlier in the same iterator.
+    ///
+    /// # Errors
+    ///
+    /// At the first element that can not be added, see [`add_element`](#method.add_element).
+    /// Elements before it remain added.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<Vec<Vec<usize>>, errors::AddError>
+    where
+        I: IntoIterator<Item = ElementExt<N, E, H, L, Vec<usize>>>,
+    {
+        iter.into_iter()
+            .map(|element| self.add_element(element))
+            .collect()
+    }
+}
+
+impl<N, E, H, L> Extend<ElementExt<N, E, H, L, Vec<usize>>> for Hypergraph<N, E, H, L, Main> {
+    /// Adds all elements in `iter` to the top level, in order.
+    ///
+    /// # Panics
+    ///
+    /// If an element can not be added, see [`try_extend`](struct.Hypergraph.html#method.try_extend).
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = ElementExt<N, E, H, L, Vec<usize>>>,
+    {
+        for element in iter {
+            self.add_element(element)
+                .expect("failed to add element while extending the hypergraph");
+        }
+    }
+}
+
+impl<N, E, H, L> FromIterator<N> for Hypergraph<N, E, H, L, Main> {
+    /// Creates a hypergraph with one node per value in `iter`, all in the top level.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+    {
+        let mut hypergraph = Self::new();
+        hypergraph.add_nodes(iter, []).unwrap(); // Never fails since the top level is a hypergraph
+        hypergraph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extend() {
+        let mut h = Hypergraph::<&str, &str>::new();
+        h.extend(vec![
+            ElementExt::Node { value: "zero" },
+            ElementExt::Node { value: "one" },
+            ElementExt::Edge {
+                source: vec![0],
+                target: vec![1],
+                value: "two",
+            },
+        ]);
+        assert_eq!(h.node_count(), 2);
+        assert_eq!(h.edge_value([2]).unwrap(), &"two");
+        assert_eq!(h.link_count(), 2);
+    }
+
+    #[test]
+    fn from_edges() {
+        let h = Hypergraph::<(), _>::from_edges(vec![(0, 1, "a"), (1, 2, "b"), (2, 0, "c")]);
+        assert_eq!(h.node_count(), 3);
+        assert_eq!(h.edge_count(), 3);
+        assert_eq!(h.edge_value([3]), Ok(&"a"));
+        assert_eq!(h.edge_endpoints([6]), Ok((vec![&vec![1]], vec![&vec![2]])));
+        assert!(h.path_exists([0], [2], crate::Direction::Outgoing));
+        assert_eq!(Hypergraph::<(), ()>::from_edges(vec![]).element_count(), 1);
+    }
+
+    #[test]
+    fn from_iter() {
+        let h: Hypergraph<_, ()> = vec!["a", "b"].into_iter().collect();
+        assert_eq!(h.node_count(), 2);
+        assert_eq!(h.node_value([0]).unwrap(), &"a");
+        assert_eq!(h.node_value([1]).unwrap(), &"b");
+        assert_eq!(h.element_count(), 3);
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut h = Hypergraph::<&str, &str>::new();
+        let ids = h
+            .try_extend(vec![
+                ElementExt::Node { value: "zero" },
+                ElementExt::Node { value: "one" },
+                ElementExt::Edge {
+                    source: vec![0],
+                    target: vec![1],
+                    value: "two",
+                },
+            ])
+            .unwrap();
+        assert_eq!(ids, vec![vec![0], vec![1], vec![2]]);
+
+        let result = h.try_extend(vec![
+            ElementExt::Node { value: "five" },
+            ElementExt::Edge {
+                source: vec![0],
+                target: vec![],
+                value: "six",
+            },
+            ElementExt::Node { value: "seven" },
+        ]);
+        assert_eq!(
+            result,
+            Err(errors::AddError::EmptyTarget(errors::EmptyTarget))
+        );
+        assert_eq!(h.node_count(), 3);
+    }
 }
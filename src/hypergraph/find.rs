@@ -1,9 +1,74 @@
-use crate::{elements::ElementValue, errors, Hypergraph};
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{elements::ElementValue, errors, Direction, Hypergraph};
 
 /// # Find
 ///
 /// Find elements.
 impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
+    /// Returns `true` if there is a link between `one` and `other`, in any direction.
+    ///
+    /// Since links always go between an edge and a node or hypergraph, an element is never linked to itself,
+    /// even if it is both the source and the target of an edge.
+    pub fn are_linked(&self, one: impl AsRef<[usize]>, other: impl AsRef<[usize]>) -> bool {
+        let other = other.as_ref();
+        match self.links_of(one) {
+            Err(_) => false,
+            Ok(links) => links.iter().any(|(link_id, direction)| {
+                let (source, target) = self.link_endpoints(link_id).unwrap(); // Never fails since link_id refers to a link
+                match direction {
+                    Direction::Incoming => source == other,
+                    Direction::Outgoing => target == other,
+                }
+            }),
+        }
+    }
+
+    /// Returns the id of the first edge that belongs to hypergraph `location`
+    /// with an incoming link from `source` and an outgoing link to `target`.
+    ///
+    /// An empty `location` means the main hypergraph.
+    ///
+    /// # Errors
+    ///
+    /// If `location` does not refer to a hypergraph, or if there is no such edge.
+    pub fn find_edge_between(
+        &self,
+        source: impl AsRef<[usize]>,
+        target: impl AsRef<[usize]>,
+        location: impl AsRef<[usize]>,
+    ) -> Result<Vec<usize>, errors::FindError> {
+        let location = location.as_ref();
+        let source = source.as_ref();
+        let target = target.as_ref();
+        if !self.contains_hypergraph(location) {
+            Err(errors::NoHypergraph(location.to_vec()))?
+        }
+        let hypergraph = self.hypergraph_at(location).unwrap(); // Never fails since location refers to a hypergraph
+        let is_linked =
+            |links: &Vec<(Vec<usize>, Direction)>, direction: Direction, id: &[usize]| {
+                links.iter().any(|(link_id, link_direction)| {
+                    // Never fails since link_id refers to a link
+                    let (link_source, link_target) = self.link_endpoints(link_id).unwrap();
+                    *link_direction == direction
+                        && match direction {
+                            Direction::Incoming => link_source == id,
+                            Direction::Outgoing => link_target == id,
+                        }
+                })
+            };
+        for (local_id, (_, links)) in hypergraph.raw_edges() {
+            if is_linked(links, Direction::Incoming, source)
+                && is_linked(links, Direction::Outgoing, target)
+            {
+                let mut id = location.to_vec();
+                id.push(*local_id);
+                return Ok(id);
+            }
+        }
+        Err(errors::FindError::NoEdge)
+    }
+
     /// Returns the id of the link that belongs to hypergraph `location` linking `source` and `target`.
     ///
     /// An empty `location` means the main hypergraph.
@@ -24,7 +89,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         if !self.contains_hypergraph(location) {
             Err(errors::NoHypergraph(location.to_vec()))?
         }
-        let hypergraph = self.hypergraph(location).unwrap(); // Never fails since location refers to a hypergraph
+        let hypergraph = self.hypergraph_at(location).unwrap(); // Never fails since location refers to a hypergraph
         let links = hypergraph.raw_links();
         let source = source.as_ref().to_vec();
         let target = target.as_ref().to_vec();
@@ -38,6 +103,60 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         Err(errors::FindError::NoLink)
     }
 
+    /// Returns the ids of all links that belong to hypergraph `location` linking `source` and `target`,
+    /// regardless of their value.
+    ///
+    /// An empty `location` means the main hypergraph.
+    ///
+    /// If `location` does not refer to a hypergraph, the result is empty.
+    pub fn links_between(
+        &self,
+        source: impl AsRef<[usize]>,
+        target: impl AsRef<[usize]>,
+        location: impl AsRef<[usize]>,
+    ) -> Vec<Vec<usize>> {
+        let location = location.as_ref();
+        let source = source.as_ref();
+        let target = target.as_ref();
+        let hypergraph = match self.hypergraph_at(location) {
+            Ok(hypergraph) => hypergraph,
+            Err(_) => return Vec::new(),
+        };
+        hypergraph
+            .raw_links()
+            .iter()
+            .filter(|(_, link_full)| link_full.1 == source && link_full.2 == target)
+            .map(|(local_id, _)| {
+                let mut id = location.to_vec();
+                id.push(*local_id);
+                id
+            })
+            .collect()
+    }
+
+    /// Returns the number of links that belong to hypergraph `location` linking `source` and `target`,
+    /// regardless of their value.
+    ///
+    /// This is the length of [`links_between`](#method.links_between), without collecting the ids.
+    /// If `location` does not refer to a hypergraph, the result is zero.
+    pub fn multiplicity(
+        &self,
+        source: impl AsRef<[usize]>,
+        target: impl AsRef<[usize]>,
+        location: impl AsRef<[usize]>,
+    ) -> usize {
+        let source = source.as_ref();
+        let target = target.as_ref();
+        match self.hypergraph_at(location) {
+            Ok(hypergraph) => hypergraph
+                .raw_links()
+                .values()
+                .filter(|link_full| link_full.1 == source && link_full.2 == target)
+                .count(),
+            Err(_) => 0,
+        }
+    }
+
     pub fn find_element_by_value(
         &self,
         value: ElementValue<&N, &E, &H, &L>,
@@ -118,6 +237,66 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             .map(|(id, _)| id)
             .ok_or(errors::FindError::NoNode)
     }
+
+    /// Returns the ids of all edges, in all levels, grouped by their value.
+    ///
+    /// Ids in each group are in the order of [`ids`](#method.ids).
+    pub fn group_edges_by_value(&self) -> HashMap<&E, Vec<Vec<usize>>>
+    where
+        E: Eq + Hash,
+    {
+        let mut groups: HashMap<&E, Vec<Vec<usize>>> = HashMap::new();
+        for id in self.ids().filter(|id| self.contains_edge(id)) {
+            let edge_value = self.edge_value(&id).unwrap(); // Never fails since id refers to an edge
+            groups.entry(edge_value).or_default().push(id);
+        }
+        groups
+    }
+
+    /// Returns the ids of all subhypergraphs, in all levels, grouped by their value.
+    ///
+    /// Ids in each group are in the order of [`ids`](#method.ids).
+    pub fn group_hypergraphs_by_value(&self) -> HashMap<&Option<H>, Vec<Vec<usize>>>
+    where
+        H: Eq + Hash,
+    {
+        let mut groups: HashMap<&Option<H>, Vec<Vec<usize>>> = HashMap::new();
+        for id in self.ids().filter(|id| self.contains_hypergraph(id)) {
+            let hypergraph_value = self.hypergraph_value(&id).unwrap(); // Never fails since id refers to a hypergraph
+            groups.entry(hypergraph_value).or_default().push(id);
+        }
+        groups
+    }
+
+    /// Returns the ids of all links, in all levels, grouped by their value.
+    ///
+    /// Ids in each group are in the order of [`ids`](#method.ids).
+    pub fn group_links_by_value(&self) -> HashMap<&Option<L>, Vec<Vec<usize>>>
+    where
+        L: Eq + Hash,
+    {
+        let mut groups: HashMap<&Option<L>, Vec<Vec<usize>>> = HashMap::new();
+        for id in self.ids().filter(|id| self.contains_link(id)) {
+            let link_value = self.link_value(&id).unwrap(); // Never fails since id refers to a link
+            groups.entry(link_value).or_default().push(id);
+        }
+        groups
+    }
+
+    /// Returns the ids of all nodes, in all levels, grouped by their value.
+    ///
+    /// Ids in each group are in the order of [`ids`](#method.ids).
+    pub fn group_nodes_by_value(&self) -> HashMap<&N, Vec<Vec<usize>>>
+    where
+        N: Eq + Hash,
+    {
+        let mut groups: HashMap<&N, Vec<Vec<usize>>> = HashMap::new();
+        for id in self.ids().filter(|id| self.contains_node(id)) {
+            let node_value = self.node_value(&id).unwrap(); // Never fails since id refers to a node
+            groups.entry(node_value).or_default().push(id);
+        }
+        groups
+    }
 }
 
 #[cfg(test)]
@@ -125,6 +304,26 @@ mod tests {
     use super::*;
     // use test_case::test_case;
 
+    #[test]
+    fn find_edge_between() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_edge([1], [0], "five").unwrap();
+
+        assert_eq!(h.find_edge_between([0], [1], []), Ok(vec![2]));
+        assert_eq!(h.find_edge_between([1], [0], []), Ok(vec![5]));
+        assert_eq!(
+            h.find_edge_between([0], [0], []),
+            Err(errors::FindError::NoEdge)
+        );
+        assert_eq!(
+            h.find_edge_between([0], [1], [9]),
+            Err(errors::FindError::NoLocation(errors::NoHypergraph(vec![9])))
+        );
+    }
+
     #[test]
     fn find_link_id() {
         // Links without values
@@ -153,4 +352,80 @@ mod tests {
         let result = h.find_link_id(&node_0_id, &edge_id, link_value, []);
         assert_eq!(result, Ok(vec![5]));
     }
+
+    #[test]
+    fn links_between() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "five").unwrap();
+
+        assert_eq!(h.links_between([0], [2], []), vec![vec![3], vec![5]]);
+        assert_eq!(h.links_between([2], [1], []), vec![vec![4]]);
+        assert_eq!(h.links_between([2], [0], []), Vec::<Vec<usize>>::new());
+        assert_eq!(h.links_between([0], [2], [0]), Vec::<Vec<usize>>::new());
+    }
+
+    #[test]
+    fn multiplicity() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "five").unwrap();
+        h.add_link([0], [2], "six").unwrap();
+
+        assert_eq!(h.multiplicity([0], [2], []), 3);
+        assert_eq!(h.multiplicity([2], [1], []), 1);
+        assert_eq!(h.multiplicity([2], [0], []), 0);
+        assert_eq!(h.multiplicity([0], [2], [0]), 0);
+    }
+
+    #[test]
+    fn are_linked() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+
+        assert!(h.are_linked([0], [2]));
+        assert!(h.are_linked([2], [0]));
+        assert!(h.are_linked([1], [2]));
+        assert!(!h.are_linked([0], [1]));
+        assert!(!h.are_linked([3], [0]));
+        assert!(!h.are_linked([0], [0]));
+        assert!(!h.are_linked([2], [2]));
+
+        // Self-edge
+        h.add_edge([0], [0], "five").unwrap();
+        assert!(h.are_linked([0], [5]));
+        assert!(!h.are_linked([0], [0]));
+        assert!(!h.are_linked([5], [5]));
+    }
+
+    #[test]
+    fn group_by_value() {
+        let mut h = Hypergraph::<_, _, _, _>::new();
+        h.add_node("a");
+        h.add_node("b");
+        h.add_node("a");
+        h.add_edge([0], [1], "e").unwrap();
+        h.add_edge([1], [2], "e").unwrap();
+        h.add_hypergraph("h");
+        h.add_node_in("a", [9]).unwrap();
+        h.add_link([0], [3], "l").unwrap();
+
+        let nodes = h.group_nodes_by_value();
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[&"a"], vec![vec![0], vec![2], vec![9, 0]]);
+        assert_eq!(nodes[&"b"], vec![vec![1]]);
+        let edges = h.group_edges_by_value();
+        assert_eq!(edges[&"e"], vec![vec![3], vec![6]]);
+        let hypergraphs = h.group_hypergraphs_by_value();
+        assert_eq!(hypergraphs[&Some("h")], vec![vec![9]]);
+        let links = h.group_links_by_value();
+        assert_eq!(links[&None].len(), 4);
+        assert_eq!(links[&Some("l")], vec![vec![10]]);
+    }
 }
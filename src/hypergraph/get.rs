@@ -1,17 +1,32 @@
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 use crate::{
     direction::Direction,
     elements::{ElementType, ElementValue},
     errors, iterators,
     traits::Walker,
-    walkers, Hypergraph, HypergraphEnum, Sub,
+    walkers, EdgeEndpoints, ElementLinks, Hypergraph, HypergraphEnum, HypergraphRef,
+    HypergraphRefMut, LinkEntry, Sub, SubhypergraphEntry,
 };
 
+/// Subhypergraphs with their ids, borrowed from the hypergraph that contains them.
+type Subhypergraphs<'a, N, E, H, L> = Vec<(Vec<usize>, &'a Hypergraph<N, E, H, L, Sub>)>;
+
 /// # Get
 ///
 /// Access node and edge weights (associated data).
 impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
+    /// Returns the ids of all hypergraphs enclosing the element `id`, from the root to its [`parent`](#method.parent).
+    ///
+    /// Returns an empty vector if `id` does not refer to an element or is the root.
+    pub fn ancestry(&self, id: impl AsRef<[usize]>) -> Vec<Vec<usize>> {
+        let id = id.as_ref();
+        if id.is_empty() || !self.contains(id) {
+            return Vec::new();
+        }
+        (0..id.len()).map(|len| id[0..len].to_vec()).collect()
+    }
+
     /// Returns the class marker.
     pub fn class(&self) -> &Ty {
         &self.class
@@ -22,12 +37,89 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
     //     EdgeIterIds::new(&self)
     // }
 
+    /// Returns the sources and targets of the edge `id`.
+    ///
+    /// Sources are the sources of incoming links of the edge, and targets are the targets of outgoing links.
+    ///
+    /// # Errors
+    ///
+    /// If `id` does not refer to an edge.
+    pub fn edge_endpoints(
+        &self,
+        id: impl AsRef<[usize]>,
+    ) -> Result<EdgeEndpoints<'_>, errors::GetError> {
+        let id = id.as_ref();
+        if !self.contains_edge(id) {
+            Err(errors::NoEdge(id.to_vec()))?
+        }
+        let mut sources = Vec::new();
+        let mut targets = Vec::new();
+        for (link_id, direction) in self.links_of(id).unwrap() {
+            let (source, target) = self.link_endpoints(link_id).unwrap(); // Never fails since link_id refers to a link
+            match direction {
+                Direction::Incoming => sources.push(source),
+                Direction::Outgoing => targets.push(target),
+            }
+        }
+        Ok((sources, targets))
+    }
+
+    /// Returns all elements incident to the edge `id`, paired with their role,
+    /// in the order their links were attached to the edge.
+    ///
+    /// Sources of the edge are paired with `Incoming` (their link goes into the edge),
+    /// and targets with `Outgoing` (their link goes out of the edge).
+    /// An element linked several times to the edge appears once per link.
+    ///
+    /// # Errors
+    ///
+    /// If `id` does not refer to an edge.
+    pub fn edge_incidences(
+        &self,
+        id: impl AsRef<[usize]>,
+    ) -> Result<Vec<(Direction, Vec<usize>)>, errors::GetError> {
+        let id = id.as_ref();
+        if !self.contains_edge(id) {
+            Err(errors::NoEdge(id.to_vec()))?
+        }
+        let incidences = self
+            .links_of(id)
+            .unwrap() // Never fails since id refers to an edge
+            .iter()
+            .map(|(link_id, direction)| {
+                let (source, target) = self.link_endpoints(link_id).unwrap(); // Never fails since link_id refers to a link
+                match direction {
+                    Direction::Incoming => (Direction::Incoming, source.clone()),
+                    Direction::Outgoing => (Direction::Outgoing, target.clone()),
+                }
+            })
+            .collect();
+        Ok(incidences)
+    }
+
+    /// Returns the ids of all links of the edge `id`, in the order they were attached to it.
+    ///
+    /// For an edge created with [`add_edge`](#method.add_edge) and not linked further,
+    /// these are its incoming and its outgoing link.
+    ///
+    /// # Errors
+    ///
+    /// If `id` does not refer to an edge.
+    pub fn edge_links(&self, id: impl AsRef<[usize]>) -> Result<Vec<Vec<usize>>, errors::GetError> {
+        let id = id.as_ref();
+        if !self.contains_edge(id) {
+            Err(errors::NoEdge(id.to_vec()))?
+        }
+        let links = self.links_of(id).unwrap(); // Never fails since id refers to an edge
+        Ok(links.iter().map(|(link_id, _)| link_id.clone()).collect())
+    }
+
     pub fn edge_value(&self, id: impl AsRef<[usize]>) -> Result<&E, errors::GetError> {
         let id = id.as_ref();
-        if !self.contains_edge(&id) {
+        if !self.contains_edge(id) {
             Err(errors::NoEdge(id.to_vec()))?
         }
-        let hypergraph = self.hypergraph_of(&id).unwrap(); // Never fails since id refers to a valid edge
+        let hypergraph = self.hypergraph_of(id).unwrap(); // Never fails since id refers to a valid edge
         let local_id = id.last().unwrap(); // Never fails since id refers to a valid edge
         let edge_value = hypergraph
             .raw_edges()
@@ -39,10 +131,10 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
 
     pub fn edge_value_mut(&mut self, id: impl AsRef<[usize]>) -> Result<&mut E, errors::GetError> {
         let id = id.as_ref();
-        if !self.contains_edge(&id) {
+        if !self.contains_edge(id) {
             Err(errors::NoEdge(id.to_vec()))?
         }
-        let hypergraph = self.hypergraph_of_mut(&id).unwrap(); // Never fails since id refers to a valid edge
+        let hypergraph = self.hypergraph_of_mut(id).unwrap(); // Never fails since id refers to a valid edge
         let local_id = id.last().unwrap(); // Never fails since id refers to a valid edge
         let edge_value = hypergraph
             .raw_edges_mut()
@@ -52,6 +144,23 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         Ok(edge_value)
     }
 
+    /// Returns the values of the edges `ids`, in the same order.
+    ///
+    /// Each entry is the result of [`edge_value`](#method.edge_value) for the corresponding id.
+    pub fn edge_values(&self, ids: &[Vec<usize>]) -> Vec<Result<&E, errors::GetError>> {
+        ids.iter().map(|id| self.edge_value(id)).collect()
+    }
+
+    /// Returns an iterator over all edges of `self`, in all levels, with mutable access to their values.
+    ///
+    /// Edges are visited in the same order as in [`ids`](#method.ids).
+    pub fn edges_mut(&mut self) -> impl Iterator<Item = (Vec<usize>, &mut E)> {
+        let mut edges = Vec::new();
+        self.collect_edges_mut(&[], &mut edges);
+        edges.sort_unstable_by(|(id, _), (other_id, _)| id.cmp(other_id));
+        edges.into_iter()
+    }
+
     pub fn element_type(&self, id: impl AsRef<[usize]>) -> Result<ElementType, errors::GetError> {
         self.element_value(id)
             .map(|element| -> ElementType { element.into() })
@@ -70,7 +179,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
 
         let local_id = id.last().unwrap(); // Never fails by previous check
 
-        let hypergraph = self.hypergraph_of(&id)?;
+        let hypergraph = self.hypergraph_of(id)?;
 
         let element;
         if let Some(edge_full) = hypergraph.raw_edges().get(local_id) {
@@ -85,7 +194,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             return Ok(element);
         } else if let Some(link_full) = hypergraph.raw_links().get(local_id) {
             element = ElementValue::Link {
-                value: (&link_full.0).as_ref(),
+                value: link_full.0.as_ref(),
             };
             return Ok(element);
         } else if let Some(node_full) = hypergraph.raw_nodes().get(local_id) {
@@ -109,7 +218,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         }
 
         let local_id = id.last().unwrap(); // Never fails by previous check
-        let mut hypergraph = self.hypergraph_of_mut(&id)?;
+        let mut hypergraph = self.hypergraph_of_mut(id)?;
 
         let element = match hypergraph.element_type([*local_id])? {
             ElementType::Edge => {
@@ -142,14 +251,31 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
 
     /// Returns the hypergraph with id `id`, if it exists.
     ///
-    /// `None` is returned when the element does not exists.
+    /// Deprecated alias of [`hypergraph_at`](#method.hypergraph_at).
+    #[deprecated(since = "0.0.2", note = "use `hypergraph_at` instead")]
     pub fn hypergraph(
         &self,
         id: impl AsRef<[usize]>,
-    ) -> Result<HypergraphEnum<&Self, &Hypergraph<N, E, H, L, Sub>>, errors::GetError> {
+    ) -> Result<HypergraphRef<'_, N, E, H, L, Ty>, errors::GetError> {
+        self.hypergraph_at(id)
+    }
+
+    /// Returns the hypergraph with id `id`, if it exists.
+    ///
+    /// This is the uniform accessor to (sub-)hypergraphs: an empty `id` refers to `self`,
+    /// which is returned as `HypergraphEnum::Original`. Use [`subhypergraph`](#method.subhypergraph)
+    /// only when `id` is known to refer to a subhypergraph.
+    ///
+    /// # Errors
+    ///
+    /// If `id` does not correspond to a hypergraph.
+    pub fn hypergraph_at(
+        &self,
+        id: impl AsRef<[usize]>,
+    ) -> Result<HypergraphRef<'_, N, E, H, L, Ty>, errors::GetError> {
         let id = id.as_ref();
         if id.is_empty() {
-            return Ok(HypergraphEnum::Original(&self));
+            return Ok(HypergraphEnum::Original(self));
         }
         let h = self.subhypergraph(id)?;
         Ok(HypergraphEnum::Sub(h))
@@ -157,33 +283,48 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
 
     /// Returns the hypergraph with id `id`, if it exists.
     ///
-    /// `None` is returned when the element does not exists.
-    pub fn hypergraph_mut(
+    /// Mutable version of [`hypergraph_at`](#method.hypergraph_at).
+    ///
+    /// # Errors
+    ///
+    /// If `id` does not correspond to a hypergraph.
+    pub fn hypergraph_at_mut(
         &mut self,
         id: impl AsRef<[usize]>,
-    ) -> Result<HypergraphEnum<&mut Self, &mut Hypergraph<N, E, H, L, Sub>>, errors::GetError> {
+    ) -> Result<HypergraphRefMut<'_, N, E, H, L, Ty>, errors::GetError> {
         let id = id.as_ref();
         if id.is_empty() {
             return Ok(HypergraphEnum::Original(self));
         }
-        let subhypergraph = self.subhypergraph_mut(&id)?;
+        let subhypergraph = self.subhypergraph_mut(id)?;
         Ok(HypergraphEnum::Sub(subhypergraph))
     }
 
+    /// Returns the hypergraph with id `id`, if it exists.
+    ///
+    /// Deprecated alias of [`hypergraph_at_mut`](#method.hypergraph_at_mut).
+    #[deprecated(since = "0.0.2", note = "use `hypergraph_at_mut` instead")]
+    pub fn hypergraph_mut(
+        &mut self,
+        id: impl AsRef<[usize]>,
+    ) -> Result<HypergraphRefMut<'_, N, E, H, L, Ty>, errors::GetError> {
+        self.hypergraph_at_mut(id)
+    }
+
     /// Returns the hypergraph in which `id` lives, if it exists.
     ///
     /// `None` is returned when: there is no element with id `id`; or `id` is empty.
     pub fn hypergraph_of(
         &self,
         id: impl AsRef<[usize]>,
-    ) -> Result<HypergraphEnum<&Self, &Hypergraph<N, E, H, L, Sub>>, errors::GetError> {
+    ) -> Result<HypergraphRef<'_, N, E, H, L, Ty>, errors::GetError> {
         let id = id.as_ref();
         match id.len() {
             0 => Err(errors::RootHypergraph)?,
-            1 => Ok(HypergraphEnum::Original(&self)),
+            1 => Ok(HypergraphEnum::Original(self)),
             _ => {
                 let id = &id[0..id.len() - 1];
-                let subhypergraph = self.subhypergraph(&id)?;
+                let subhypergraph = self.subhypergraph(id)?;
                 Ok(HypergraphEnum::Sub(subhypergraph))
             }
         }
@@ -195,64 +336,50 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
     pub fn hypergraph_of_mut(
         &mut self,
         id: impl AsRef<[usize]>,
-    ) -> Result<HypergraphEnum<&mut Self, &mut Hypergraph<N, E, H, L, Sub>>, errors::GetError> {
+    ) -> Result<HypergraphRefMut<'_, N, E, H, L, Ty>, errors::GetError> {
         let id = id.as_ref();
         match id.len() {
             0 => Err(errors::RootHypergraph)?,
             1 => Ok(HypergraphEnum::Original(self)),
             _ => {
                 let id = &id[0..id.len() - 1];
-                let subhypergraph = self.subhypergraph_mut(&id)?;
+                let subhypergraph = self.subhypergraph_mut(id)?;
                 Ok(HypergraphEnum::Sub(subhypergraph))
             }
         }
     }
 
+    /// Returns the value of the hypergraph `id`.
+    ///
+    /// # Errors
+    ///
+    /// If `id` does not refer to a hypergraph. The error carries the shortest prefix of `id`
+    /// that does not refer to a hypergraph.
     pub fn hypergraph_value(
         &self,
         id: impl AsRef<[usize]>,
     ) -> Result<&Option<H>, errors::GetError> {
         let id = id.as_ref();
-        if !self.contains_hypergraph(id) {
-            Err(errors::NoHypergraph(id.to_vec()))?
-        }
-
         match id.len() {
             0 => Ok(self.value()),
-            _ => {
-                let hypergraph = self.hypergraph_of(&id)?;
-                let local_id = id.last().unwrap(); // Never fails since id is non empty.
-                let hypergraph_value = hypergraph
-                    .raw_hypergraphs()
-                    .get(local_id)
-                    .map(|hypergraph_full| &hypergraph_full.0.value)
-                    .unwrap(); // Never fails since id refers to a hypergraph
-                Ok(hypergraph_value)
-            }
+            _ => Ok(&self.subhypergraph(id)?.value),
         }
     }
 
+    /// Returns the value of the hypergraph `id`, mutably.
+    ///
+    /// # Errors
+    ///
+    /// If `id` does not refer to a hypergraph. The error carries the shortest prefix of `id`
+    /// that does not refer to a hypergraph.
     pub fn hypergraph_value_mut(
         &mut self,
         id: impl AsRef<[usize]>,
     ) -> Result<&mut Option<H>, errors::GetError> {
         let id = id.as_ref();
-        if !self.contains_hypergraph(id) {
-            Err(errors::NoHypergraph(id.to_vec()))?
-        }
-
         match id.len() {
             0 => Ok(self.value_mut()),
-            _ => {
-                let hypergraph = self.hypergraph_of_mut(&id)?;
-                let local_id = id.last().unwrap(); // Never fails since id is non empty.
-                let hypergraph_value = hypergraph
-                    .raw_hypergraphs_mut()
-                    .get_mut(local_id)
-                    .map(|hypergraph_full| &mut hypergraph_full.0.value)
-                    .unwrap(); // Never fails since id refers to a hypergraph
-                Ok(hypergraph_value)
-            }
+            _ => Ok(&mut self.subhypergraph_mut(id)?.value),
         }
     }
 
@@ -262,16 +389,105 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         // IterIds::new(&self)
     }
 
+    /// Returns an iterator over all valid ids of `self`, level by level.
+    ///
+    /// All elements of a hypergraph are visited before descending into any of its subhypergraphs,
+    /// while [`ids`](#method.ids) follows the lexicographic order.
+    pub fn ids_breadth_first<'a>(
+        &'a self,
+    ) -> iterators::WalkIter<'a, N, E, H, L, Ty, walkers::WalkIdsBreadthFirst> {
+        walkers::WalkIdsBreadthFirst::new().build_iter(self)
+    }
+
+    /// Returns an iterator over all valid ids of `self`, together with the label given by `f`.
+    ///
+    /// Ids are visited in the same order as in [`ids`](#method.ids),
+    /// and `f` is called exactly once per id.
+    pub fn ids_with_labels<'a, F>(&'a self, f: F) -> impl Iterator<Item = (Vec<usize>, String)> + 'a
+    where
+        F: Fn(&Vec<usize>) -> String + 'a,
+    {
+        self.ids().map(move |id| {
+            let label = f(&id);
+            (id, label)
+        })
+    }
+
+    /// Returns an iterator over incoming neighbors.
+    ///
+    /// Equivalent to [`neighbors_directed`](#method.neighbors_directed) with `Direction::Incoming`.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
+    pub fn in_neighbors<'a>(
+        &'a self,
+        id: impl AsRef<[usize]>,
+    ) -> Result<iterators::WalkIter<'a, N, E, H, L, Ty, walkers::WalkNeighbors>, errors::GetError>
+    {
+        self.neighbors_directed(id, Direction::Incoming)
+    }
+
+    /// Returns an iterator over all links of `self`, in all levels.
+    ///
+    /// Each item is of the form `(link_id, source_id, target_id, link_value)`.
+    pub fn iter_links(
+        &self,
+    ) -> impl Iterator<Item = (Vec<usize>, Vec<usize>, Vec<usize>, &Option<L>)> + '_ {
+        self.ids()
+            .filter(move |id| self.contains_link(id))
+            .map(move |id| {
+                let (source, target) = self.link_endpoints(&id).unwrap(); // Never fails since id refers to a link
+                let value = self.link_value(&id).unwrap(); // Never fails since id refers to a link
+                (id, source.clone(), target.clone(), value)
+            })
+    }
+
+    /// Returns an iterator over all subhypergraphs of `self`, in all levels, together with their ids.
+    ///
+    /// Subhypergraphs are visited in pre-order, that is, each one is followed by its own subhypergraphs.
+    pub fn iter_subhypergraphs(
+        &self,
+    ) -> impl Iterator<Item = (Vec<usize>, &Hypergraph<N, E, H, L, Sub>)> {
+        let mut subhypergraphs = Vec::new();
+        self.collect_subhypergraphs(&[], &mut subhypergraphs);
+        subhypergraphs.into_iter()
+    }
+
+    /// Returns the direction of the link `link_id` from the perspective of the element `id`.
+    ///
+    /// That is, `Outgoing` if `id` is the source of the link and `Incoming` if it is the target.
+    ///
+    /// # Errors
+    ///
+    /// If `link_id` does not refer to a link, or if `id` is not one of its endpoints.
+    pub fn link_direction(
+        &self,
+        link_id: impl AsRef<[usize]>,
+        id: impl AsRef<[usize]>,
+    ) -> Result<Direction, errors::GetError> {
+        let link_id = link_id.as_ref();
+        let id = id.as_ref();
+        let (source, target) = self.link_endpoints(link_id)?;
+        if source == id {
+            Ok(Direction::Outgoing)
+        } else if target == id {
+            Ok(Direction::Incoming)
+        } else {
+            Err(errors::NotEndpoint(link_id.to_vec(), id.to_vec()))?
+        }
+    }
+
     /// Returns the pair of gloalbal `id`s `(source, target)` if the link exists.
     pub fn link_endpoints(
         &self,
         id: impl AsRef<[usize]>,
     ) -> Result<(&Vec<usize>, &Vec<usize>), errors::GetError> {
         let id = id.as_ref();
-        if !self.contains_link(&id) {
+        if !self.contains_link(id) {
             Err(errors::NoLink(id.to_vec()))?
         }
-        let hypergraph = self.hypergraph_of(&id).unwrap(); // Never fails since id refers to a valid link
+        let hypergraph = self.hypergraph_of(id).unwrap(); // Never fails since id refers to a valid link
         let local_id = id.last().unwrap(); // Never fails since id refers to a valid link
         let link_endpoints = hypergraph
             .raw_links()
@@ -281,16 +497,41 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         Ok(link_endpoints)
     }
 
-    /// Returns the links of an element of the current hypergraph, `None` if the element does not exists or is a link.
-    pub fn links_of(
+    /// Returns the links of an element of the current hypergraph in `direction`,
+    /// or in both directions if `direction` is `None`.
+    ///
+    /// Links are given in the same order as [`links_of`](#method.links_of).
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
+    pub fn links_of_directed(
         &self,
         id: impl AsRef<[usize]>,
-    ) -> Result<&Vec<(Vec<usize>, Direction)>, errors::GetError> {
+        direction: impl Into<Option<Direction>>,
+    ) -> Result<impl Iterator<Item = &(Vec<usize>, Direction)> + '_, errors::GetError> {
+        let direction = direction.into();
+        let links = self.links_of(id)?;
+        #[allow(clippy::unnecessary_map_or)]
+        Ok(links
+            .iter()
+            .filter(move |(_, link_direction)| direction.map_or(true, |d| d == *link_direction)))
+    }
+
+    /// Returns the links of an element of the current hypergraph.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
+    pub fn links_of(&self, id: impl AsRef<[usize]>) -> Result<&ElementLinks, errors::GetError> {
         let id = id.as_ref();
-        if !self.contains_linkable(&id) {
+        if self.contains_link(id) {
+            Err(errors::IsLink(id.to_vec()))?;
+        }
+        if !self.contains_linkable(id) {
             Err(errors::NoElementLinkable(id.to_vec()))?;
         }
-        let hypergraph = self.hypergraph_of(&id)?;
+        let hypergraph = self.hypergraph_of(id)?;
         let local_id = id.last().unwrap(); // Never fails since id refers to a linkable element
         let links = match hypergraph.element_type([*local_id]).unwrap() // Never fails since id refers to a linkable element
         {
@@ -320,7 +561,11 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         Ok(links)
     }
 
-    /// Returns the links of an element of the current hypergraph, `None` if the element does not exists or is a link.
+    /// Returns the links of an element of the current hypergraph.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
     ///
     /// # Notes
     ///
@@ -329,12 +574,15 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
     pub(crate) fn links_of_mut(
         &mut self,
         id: impl AsRef<[usize]>,
-    ) -> Result<&mut Vec<(Vec<usize>, Direction)>, errors::GetError> {
+    ) -> Result<&mut ElementLinks, errors::GetError> {
         let id = id.as_ref();
-        if !self.contains_linkable(&id) {
+        if self.contains_link(id) {
+            Err(errors::IsLink(id.to_vec()))?;
+        }
+        if !self.contains_linkable(id) {
             Err(errors::NoElementLinkable(id.to_vec()))?;
         }
-        let mut hypergraph = self.hypergraph_of_mut(&id)?;
+        let mut hypergraph = self.hypergraph_of_mut(id)?;
         let local_id = id.last().unwrap(); // Never fails since id refers to a linkable element
         let links = match hypergraph.element_type([*local_id]).unwrap() // Never fails since id refers to a linkable element
         {
@@ -366,10 +614,10 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
 
     pub fn link_value(&self, id: impl AsRef<[usize]>) -> Result<&Option<L>, errors::GetError> {
         let id = id.as_ref();
-        if !self.contains_link(&id) {
+        if !self.contains_link(id) {
             Err(errors::NoLink(id.to_vec()))?
         }
-        let hypergraph = self.hypergraph_of(&id).unwrap(); // Never fails since id refers to a valid link
+        let hypergraph = self.hypergraph_of(id).unwrap(); // Never fails since id refers to a valid link
         let local_id = id.last().unwrap(); // Never fails since id refers to a valid link
         let link_value = hypergraph
             .raw_links()
@@ -384,10 +632,10 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         id: impl AsRef<[usize]>,
     ) -> Result<&mut Option<L>, errors::GetError> {
         let id = id.as_ref();
-        if !self.contains_link(&id) {
+        if !self.contains_link(id) {
             Err(errors::NoLink(id.to_vec()))?
         }
-        let hypergraph = self.hypergraph_of_mut(&id).unwrap(); // Never fails since id refers to a valid link
+        let hypergraph = self.hypergraph_of_mut(id).unwrap(); // Never fails since id refers to a valid link
         let local_id = id.last().unwrap(); // Never fails since id refers to a valid link
         let link_value = hypergraph
             .raw_links_mut()
@@ -399,21 +647,66 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
 
     /// Returns an iterator over outgoing neighbors.
     ///
-    /// If `id` is not a valid element, the iterator returns always `None`.
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
     pub fn neighbors<'a>(
         &'a self,
         id: impl AsRef<[usize]>,
-    ) -> iterators::WalkIter<'a, N, E, H, L, Ty, walkers::WalkNeighbors> {
-        let direction = Direction::Outgoing;
-        walkers::WalkNeighbors::new(direction, id).build_iter(self)
+    ) -> Result<iterators::WalkIter<'a, N, E, H, L, Ty, walkers::WalkNeighbors>, errors::GetError>
+    {
+        self.neighbors_directed(id, Direction::Outgoing)
+    }
+
+    /// Returns an iterator over the values of the neighbors of `id` in `direction`.
+    ///
+    /// Neighbors are visited in the same order as in [`neighbors_directed`](#method.neighbors_directed).
+    /// If `id` is not a valid element, the iterator is empty.
+    pub fn neighbor_values(
+        &self,
+        id: impl AsRef<[usize]>,
+        direction: Direction,
+    ) -> impl Iterator<Item = ElementValue<&N, &E, &H, &L>> {
+        self.neighbors_directed(id, direction)
+            .into_iter()
+            .flatten()
+            .map(move |neighbor_id| self.element_value(neighbor_id).unwrap()) // Never fails since neighbor_id refers to an element
+    }
+
+    /// Returns an iterator over the neighbors of `id` in `direction`, together with the links traversed.
+    ///
+    /// Each item is of the form `(link_id, neighbor_id, direction)`.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
+    pub fn neighbors_with_links<'a>(
+        &'a self,
+        id: impl AsRef<[usize]>,
+        direction: Direction,
+    ) -> Result<
+        iterators::WalkIter<'a, N, E, H, L, Ty, walkers::WalkNeighborsWithLink>,
+        errors::GetError,
+    > {
+        let id = id.as_ref();
+        self.links_of(id)?;
+        Ok(walkers::WalkNeighborsWithLink::new(direction, id).build_iter(self))
     }
 
+    /// Returns an iterator over the neighbors of `id` in `direction`.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
     pub fn neighbors_directed<'a>(
         &'a self,
         id: impl AsRef<[usize]>,
         direction: Direction,
-    ) -> iterators::WalkIter<'a, N, E, H, L, Ty, walkers::WalkNeighbors> {
-        walkers::WalkNeighbors::new(direction, id).build_iter(self)
+    ) -> Result<iterators::WalkIter<'a, N, E, H, L, Ty, walkers::WalkNeighbors>, errors::GetError>
+    {
+        let id = id.as_ref();
+        self.links_of(id)?;
+        Ok(walkers::WalkNeighbors::new(direction, id).build_iter(self))
     }
 
     /// Returns the next valid id.
@@ -435,10 +728,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
                     *last_local_id += 1;
                     if *last_local_id >= bound[id.len() - 1] {
                         id.pop(); // Go back one level
-                        let last_local_id = match id.last_mut() {
-                            None => return None,
-                            Some(i) => i,
-                        };
+                        let last_local_id = id.last_mut()?;
                         *last_local_id += 1;
                     }
                 }
@@ -454,9 +744,9 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             }
         }
         if self.contains(&id) {
-            return Some(id);
+            Some(id)
         } else {
-            return self.next_id(id);
+            self.next_id(id)
         }
     }
 
@@ -474,7 +764,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         match id.len() {
             0 => Err(errors::RootHypergraph)?,
             _ => {
-                let hypergraph = self.hypergraph_of(&id)?;
+                let hypergraph = self.hypergraph_of(id)?;
                 let local_id = id.last().unwrap(); // Never fails since id is non empty.
                 let node_value = hypergraph
                     .raw_nodes()
@@ -495,7 +785,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         match id.len() {
             0 => Err(errors::RootHypergraph)?,
             _ => {
-                let hypergraph = self.hypergraph_of_mut(&id)?;
+                let hypergraph = self.hypergraph_of_mut(id)?;
                 let local_id = id.last().unwrap(); // Never fails since id is non empty.
                 let node_value = hypergraph
                     .raw_nodes_mut()
@@ -507,51 +797,130 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         }
     }
 
-    pub fn raw_edges(&self) -> &IndexMap<usize, (E, Vec<(Vec<usize>, Direction)>)> {
+    /// Returns the values of the nodes `ids`, in the same order.
+    ///
+    /// Each entry is the result of [`node_value`](#method.node_value) for the corresponding id.
+    pub fn node_values(&self, ids: &[Vec<usize>]) -> Vec<Result<&N, errors::GetError>> {
+        ids.iter().map(|id| self.node_value(id)).collect()
+    }
+
+    /// Returns an iterator over outgoing neighbors.
+    ///
+    /// Equivalent to [`neighbors_directed`](#method.neighbors_directed) with `Direction::Outgoing`.
+    ///
+    /// # Errors
+    ///
+    /// If `id` refers to a link (`IsLink`), or it does not refer to any element (`NoElementLinkable`).
+    pub fn out_neighbors<'a>(
+        &'a self,
+        id: impl AsRef<[usize]>,
+    ) -> Result<iterators::WalkIter<'a, N, E, H, L, Ty, walkers::WalkNeighbors>, errors::GetError>
+    {
+        self.neighbors_directed(id, Direction::Outgoing)
+    }
+
+    /// Returns the id of the hypergraph immediately enclosing the element `id`.
+    ///
+    /// Returns `None` if `id` does not refer to an element or is the root.
+    pub fn parent(&self, id: impl AsRef<[usize]>) -> Option<Vec<usize>> {
+        let id = id.as_ref();
+        if id.is_empty() || !self.contains(id) {
+            return None;
+        }
+        Some(id[0..id.len() - 1].to_vec())
+    }
+
+    /// Returns the previous valid id.
+    ///
+    /// Returns `None` if there is no valid id smaller than `id` (for example, if `id` is empty).
+    ///
+    /// Order is lexicographic.
+    pub fn prev_id(&self, id: impl AsRef<[usize]>) -> Option<Vec<usize>> {
+        let id = id.as_ref();
+        if id.is_empty() {
+            return None;
+        }
+        // Longest prefix of id that refers to a hypergraph
+        let mut depth = 0;
+        while depth < id.len() - 1 && self.contains_subhypergraph(&id[0..=depth]) {
+            depth += 1;
+        }
+        if depth < id.len() - 1 && self.contains(&id[0..=depth]) {
+            // A valid element that is a prefix of id (and therefore smaller)
+            return Some(id[0..=depth].to_vec());
+        }
+        let location = &id[0..depth];
+        let hypergraph = self.hypergraph_at(location).unwrap(); // Never fails since location refers to a hypergraph
+        let previous_local_id = hypergraph
+            .raw_edges()
+            .keys()
+            .chain(hypergraph.raw_hypergraphs().keys())
+            .chain(hypergraph.raw_links().keys())
+            .chain(hypergraph.raw_nodes().keys())
+            .filter(|local_id| **local_id < id[depth])
+            .max();
+        match previous_local_id {
+            None => Some(location.to_vec()),
+            Some(local_id) => {
+                let mut previous_id = location.to_vec();
+                previous_id.push(*local_id);
+                // Go to the biggest id inside, if it is a hypergraph
+                while let Ok(subhypergraph) = self.subhypergraph(&previous_id) {
+                    let last_local_id = subhypergraph
+                        .raw_edges()
+                        .keys()
+                        .chain(subhypergraph.raw_hypergraphs().keys())
+                        .chain(subhypergraph.raw_links().keys())
+                        .chain(subhypergraph.raw_nodes().keys())
+                        .max();
+                    match last_local_id {
+                        None => break,
+                        Some(local_id) => previous_id.push(*local_id),
+                    }
+                }
+                Some(previous_id)
+            }
+        }
+    }
+
+    pub fn raw_edges(&self) -> &IndexMap<usize, (E, ElementLinks)> {
         &self.edges
     }
 
-    pub(crate) fn raw_edges_mut<'a>(
-        &'a mut self,
-    ) -> &'a mut IndexMap<usize, (E, Vec<(Vec<usize>, Direction)>)> {
+    pub(crate) fn raw_edges_mut(&mut self) -> &mut IndexMap<usize, (E, ElementLinks)> {
         &mut self.edges
     }
 
-    pub fn raw_links(&self) -> &IndexMap<usize, (Option<L>, Vec<usize>, Vec<usize>)> {
+    pub fn raw_links(&self) -> &IndexMap<usize, LinkEntry<L>> {
         &self.links
     }
 
-    pub(crate) fn raw_links_mut(
-        &mut self,
-    ) -> &mut IndexMap<usize, (Option<L>, Vec<usize>, Vec<usize>)> {
+    pub(crate) fn raw_links_mut(&mut self) -> &mut IndexMap<usize, LinkEntry<L>> {
         &mut self.links
     }
 
-    pub fn raw_hypergraphs(
-        &self,
-    ) -> &IndexMap<usize, (Hypergraph<N, E, H, L, Sub>, Vec<(Vec<usize>, Direction)>)> {
+    pub fn raw_hypergraphs(&self) -> &IndexMap<usize, SubhypergraphEntry<N, E, H, L>> {
         &self.hypergraphs
     }
 
     pub(crate) fn raw_hypergraphs_mut(
         &mut self,
-    ) -> &mut IndexMap<usize, (Hypergraph<N, E, H, L, Sub>, Vec<(Vec<usize>, Direction)>)> {
+    ) -> &mut IndexMap<usize, SubhypergraphEntry<N, E, H, L>> {
         &mut self.hypergraphs
     }
 
-    pub fn raw_nodes(&self) -> &IndexMap<usize, (N, Vec<(Vec<usize>, Direction)>)> {
+    pub fn raw_nodes(&self) -> &IndexMap<usize, (N, ElementLinks)> {
         &self.nodes
     }
 
-    pub(crate) fn raw_nodes_mut(
-        &mut self,
-    ) -> &mut IndexMap<usize, (N, Vec<(Vec<usize>, Direction)>)> {
+    pub(crate) fn raw_nodes_mut(&mut self) -> &mut IndexMap<usize, (N, ElementLinks)> {
         &mut self.nodes
     }
 
     /// Returns the subgraph with id `id`, if it exists.
     ///
     /// `None` is returned when `id` is empty, or there is no (sub-)hypergraph with such `id`.
+    /// To also access `self` through the empty id, see [`hypergraph_at`](#method.hypergraph_at).
     pub fn subhypergraph(
         &self,
         id: impl AsRef<[usize]>,
@@ -613,12 +982,82 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         }
     }
 
+    /// Same as [`edge_value`](#method.edge_value), but returns `None` in case of error.
+    pub fn try_edge_value(&self, id: impl AsRef<[usize]>) -> Option<&E> {
+        self.edge_value(id).ok()
+    }
+
+    /// Same as [`hypergraph_value`](#method.hypergraph_value), but returns `None` in case of error.
+    pub fn try_hypergraph_value(&self, id: impl AsRef<[usize]>) -> Option<&Option<H>> {
+        self.hypergraph_value(id).ok()
+    }
+
+    /// Same as [`link_value`](#method.link_value), but returns `None` in case of error.
+    pub fn try_link_value(&self, id: impl AsRef<[usize]>) -> Option<&Option<L>> {
+        self.link_value(id).ok()
+    }
+
+    /// Same as [`node_value`](#method.node_value), but returns `None` in case of error.
+    pub fn try_node_value(&self, id: impl AsRef<[usize]>) -> Option<&N> {
+        self.node_value(id).ok()
+    }
+
+    /// Returns the distinct neighbors of `id` in `direction`, in order of first appearance.
+    ///
+    /// Unlike [`neighbors_directed`](#method.neighbors_directed), a neighbor connected through several links
+    /// is returned only once. The output is empty if `id` does not refer to a linkable element.
+    pub fn unique_neighbors(
+        &self,
+        id: impl AsRef<[usize]>,
+        direction: Direction,
+    ) -> Vec<Vec<usize>> {
+        let unique: IndexSet<&Vec<usize>> = self
+            .neighbors_directed(id, direction)
+            .into_iter()
+            .flatten()
+            .collect();
+        unique.into_iter().cloned().collect()
+    }
+
     pub fn value(&self) -> &Option<H> {
         &self.value
     }
-    pub fn value_mut(self: &mut Self) -> &mut Option<H> {
+    pub fn value_mut(&mut self) -> &mut Option<H> {
         &mut self.value
     }
+
+    /// Pushes the subhypergraphs of `self`, in all levels and in pre-order, into `subhypergraphs`.
+    /// Ids are prefixed by `pre_id`.
+    fn collect_subhypergraphs<'a>(
+        &'a self,
+        pre_id: &[usize],
+        subhypergraphs: &mut Subhypergraphs<'a, N, E, H, L>,
+    ) {
+        for (local_id, (hypergraph, _)) in self.hypergraphs.iter() {
+            let mut id = pre_id.to_vec();
+            id.push(*local_id);
+            subhypergraphs.push((id.clone(), hypergraph));
+            hypergraph.collect_subhypergraphs(&id, subhypergraphs);
+        }
+    }
+
+    /// Pushes the edges of `self`, in all levels, into `edges`. Ids are prefixed by `pre_id`.
+    fn collect_edges_mut<'a>(
+        &'a mut self,
+        pre_id: &[usize],
+        edges: &mut Vec<(Vec<usize>, &'a mut E)>,
+    ) {
+        for (local_id, (value, _)) in self.edges.iter_mut() {
+            let mut id = pre_id.to_vec();
+            id.push(*local_id);
+            edges.push((id, value));
+        }
+        for (local_id, (hypergraph, _)) in self.hypergraphs.iter_mut() {
+            let mut id = pre_id.to_vec();
+            id.push(*local_id);
+            hypergraph.collect_edges_mut(&id, edges);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -627,6 +1066,18 @@ mod tests {
     use crate::{traits::HypergraphClass, Main};
     use test_case::test_case;
 
+    #[test]
+    fn ancestry() {
+        let mut h = Hypergraph::<_, (), _>::new();
+        h.add_hypergraph("zero");
+        h.add_hypergraph_in("one", [0]).unwrap();
+        h.add_node_in("two", [0, 0]).unwrap();
+        assert_eq!(h.ancestry([0, 0, 0]), vec![vec![], vec![0], vec![0, 0]]);
+        assert_eq!(h.ancestry([0]), vec![Vec::<usize>::new()]);
+        assert!(h.ancestry([]).is_empty());
+        assert!(h.ancestry([0, 1]).is_empty());
+    }
+
     #[test_case(Hypergraph::<u8, u8>::new(), Main; "Main")]
     #[test_case(Hypergraph::<u8, u8, u8, u8, Sub>::new(), Sub; "Sub")]
     fn class<N, E, H, L, Ty: HypergraphClass>(h: Hypergraph<N, E, H, L, Ty>, expected: Ty) {
@@ -732,12 +1183,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ids_rev() {
+        let mut h = Hypergraph::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "three").unwrap();
+        h.add_hypergraph("six");
+        assert_eq!(h.ids().next_back(), Some(vec![6]));
+
+        h.add_node_in("seven", [6]).unwrap();
+        h.add_hypergraph_in("eight", [6]).unwrap();
+        let mut ids = h.ids().collect::<Vec<_>>();
+        ids.reverse();
+        assert_eq!(h.ids().rev().collect::<Vec<_>>(), ids);
+
+        let mut ids = h.ids();
+        assert_eq!(ids.next(), Some(vec![]));
+        assert_eq!(ids.next_back(), Some(vec![6, 1]));
+        assert_eq!(ids.next_back(), Some(vec![6, 0]));
+        assert_eq!(ids.count(), 7);
+    }
+
+    #[test]
+    fn ids_size_hint() {
+        let mut h = Hypergraph::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "three").unwrap();
+        h.add_hypergraph("six");
+        let total = h.node_count() + h.edge_count() + h.link_count() + h.hypergraph_count();
+        assert_eq!(h.ids().size_hint(), (total, Some(total)));
+        assert_eq!(h.ids().collect::<Vec<_>>().len(), total);
+    }
+
     #[test]
     fn links_of() {
         let mut h = Hypergraph::<&str, &str, &str>::new();
         h.add_node("zero");
         h.add_node("one");
-        h.add_edge([0], [1], "two");
+        h.add_edge([0], [1], "two").unwrap();
         let subhypergraph_id = h.add_hypergraph("five");
         h.add_node_in("zero", subhypergraph_id).unwrap();
 
@@ -759,6 +1246,272 @@ mod tests {
         assert_eq!(h.links_of([5, 0]).unwrap(), &vec![]);
     }
 
+    #[test]
+    fn links_of_error() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+
+        assert_eq!(
+            h.links_of([3]),
+            Err(errors::GetError::IsLink(errors::IsLink(vec![3])))
+        );
+        assert_eq!(
+            h.links_of([5]),
+            Err(errors::GetError::NoElementLinkable(
+                errors::NoElementLinkable(vec![5])
+            ))
+        );
+        assert_eq!(
+            h.degree([3]),
+            Err(errors::GetError::IsLink(errors::IsLink(vec![3])))
+        );
+        assert_eq!(
+            h.degree([5]),
+            Err(errors::GetError::NoElementLinkable(
+                errors::NoElementLinkable(vec![5])
+            ))
+        );
+    }
+
+    #[test]
+    fn edge_endpoints() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([2], [0], ()).unwrap();
+
+        assert_eq!(
+            h.edge_endpoints([2]),
+            Ok((vec![&vec![0]], vec![&vec![1], &vec![0]]))
+        );
+        assert_eq!(
+            h.edge_endpoints([0]),
+            Err(errors::GetError::NoEdge(errors::NoEdge(vec![0])))
+        );
+    }
+
+    #[test]
+    fn edges_mut() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], 2).unwrap();
+        h.add_hypergraph(());
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], 8, [5]).unwrap();
+        h.add_edge([1], [0], 11).unwrap();
+
+        for (_, e) in h.edges_mut() {
+            *e += 1;
+        }
+        assert_eq!(h.edge_value([2]), Ok(&3));
+        assert_eq!(h.edge_value([5, 2]), Ok(&9));
+        assert_eq!(h.edge_value([6]), Ok(&12));
+        let ids: Vec<_> = h.edges_mut().map(|(id, _)| id).collect();
+        assert_eq!(ids, vec![vec![2], vec![5, 2], vec![6]]);
+    }
+
+    #[test]
+    fn iter_subhypergraphs() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
+        h.add_link([2], [5, 0], ()).unwrap();
+        h.add_hypergraph_in("twelve", [5]).unwrap();
+        h.add_node_in("thirteen", [5, 5]).unwrap();
+        h.add_hypergraph("seven");
+
+        let subhypergraphs: Vec<_> = h
+            .iter_subhypergraphs()
+            .map(|(id, subhypergraph)| (id, *subhypergraph.value()))
+            .collect();
+        assert_eq!(
+            subhypergraphs,
+            vec![
+                (vec![5], Some("five")),
+                (vec![5, 5], Some("twelve")),
+                (vec![7], Some("seven"))
+            ]
+        );
+    }
+
+    #[test]
+    fn neighbor_values() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_link([2], [5], ()).unwrap();
+
+        assert_eq!(
+            h.neighbor_values([2], Direction::Outgoing)
+                .collect::<Vec<_>>(),
+            vec![
+                ElementValue::Node { value: &"one" },
+                ElementValue::Hypergraph {
+                    value: Some(&"five")
+                }
+            ]
+        );
+        assert_eq!(
+            h.neighbor_values([1], Direction::Incoming)
+                .collect::<Vec<_>>(),
+            vec![ElementValue::Edge { value: &"two" }]
+        );
+        assert_eq!(h.neighbor_values([7], Direction::Incoming).count(), 0);
+    }
+
+    #[test]
+    fn try_values() {
+        let mut h = Hypergraph::<_, _, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+
+        assert_eq!(h.try_node_value([0]), Some(&"zero"));
+        assert_eq!(h.try_node_value([2]), None);
+        assert_eq!(h.try_edge_value([2]), Some(&"two"));
+        assert_eq!(h.try_edge_value([6]), None);
+        assert_eq!(h.try_link_value([3]), Some(&None::<&str>));
+        assert_eq!(h.try_link_value([0]), None);
+        assert_eq!(h.try_hypergraph_value([5]), Some(&Some("five")));
+        assert_eq!(h.try_hypergraph_value([5, 0]), None);
+    }
+
+    #[test]
+    fn no_hypergraph_prefix() {
+        let mut h = Hypergraph::<(), (), _>::new();
+        h.add_hypergraph("zero");
+        h.add_hypergraph_in("one", [0]).unwrap();
+        h.add_node_in((), [0, 0]).unwrap();
+        let no_hypergraph =
+            |id: Vec<usize>| errors::GetError::NoHypergraph(errors::NoHypergraph(id));
+
+        assert_eq!(h.hypergraph_value([0, 0]), Ok(&Some("one")));
+        assert_eq!(
+            h.hypergraph_value([0, 1]).unwrap_err(),
+            no_hypergraph(vec![0, 1])
+        );
+        assert_eq!(
+            h.hypergraph_value([0, 1, 2]).unwrap_err(),
+            no_hypergraph(vec![0, 1])
+        );
+        assert_eq!(
+            h.hypergraph_value([1, 0, 0]).unwrap_err(),
+            no_hypergraph(vec![1])
+        );
+        assert_eq!(
+            h.hypergraph_value([0, 0, 0]).unwrap_err(),
+            no_hypergraph(vec![0, 0, 0])
+        );
+        assert_eq!(
+            h.hypergraph_value_mut([0, 2, 0]).unwrap_err(),
+            no_hypergraph(vec![0, 2])
+        );
+        assert_eq!(
+            h.subhypergraph([0, 0, 3]).unwrap_err(),
+            no_hypergraph(vec![0, 0, 3])
+        );
+        assert_eq!(
+            h.hypergraph_of([0, 3, 0, 0]).unwrap_err(),
+            no_hypergraph(vec![0, 3])
+        );
+        assert_eq!(
+            h.hypergraph_of_mut([2, 0]).unwrap_err(),
+            no_hypergraph(vec![2])
+        );
+        assert_eq!(
+            h.hypergraph_at([0, 0, 0]).unwrap_err(),
+            no_hypergraph(vec![0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn neighbors_with_links() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "five").unwrap();
+
+        let links: Vec<_> = h
+            .neighbors_with_links([0], Direction::Outgoing)
+            .unwrap()
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                (&vec![3], &vec![2], Direction::Outgoing),
+                (&vec![5], &vec![2], Direction::Outgoing)
+            ]
+        );
+        assert_eq!(h.find_link_id([0], [2], None, []), Ok(vec![3]));
+        assert_eq!(h.find_link_id([0], [2], &"five", []), Ok(vec![5]));
+        let links: Vec<_> = h
+            .neighbors_with_links([1], Direction::Incoming)
+            .unwrap()
+            .collect();
+        assert_eq!(links, vec![(&vec![4], &vec![2], Direction::Incoming)]);
+        assert_eq!(h.find_link_id([2], [1], None, []), Ok(vec![4]));
+    }
+
+    #[test]
+    fn iter_links() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
+        h.add_link([2], [5, 0], "eleven").unwrap();
+        h.add_hypergraph_in("twelve", [5]).unwrap();
+        h.add_node_in("thirteen", [5, 5]).unwrap();
+
+        let links = h.iter_links().collect::<Vec<_>>();
+        assert_eq!(links.len(), 5);
+        assert_eq!(links[0], (vec![3], vec![0], vec![2], &None));
+        assert_eq!(links[1], (vec![4], vec![2], vec![1], &None));
+        assert_eq!(links[2], (vec![5, 3], vec![5, 0], vec![5, 2], &None));
+        assert_eq!(links[3], (vec![5, 4], vec![5, 2], vec![5, 1], &None));
+        assert_eq!(links[4], (vec![6], vec![2], vec![5, 0], &Some("eleven")));
+    }
+
+    #[test]
+    fn link_direction() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        assert_eq!(h.link_direction([3], [0]), Ok(Direction::Outgoing));
+        assert_eq!(h.link_direction([3], [2]), Ok(Direction::Incoming));
+        assert_eq!(h.link_direction([4], [2]), Ok(Direction::Outgoing));
+        assert_eq!(h.link_direction([4], [1]), Ok(Direction::Incoming));
+        assert_eq!(
+            h.link_direction([3], [1]),
+            Err(errors::GetError::NotEndpoint(errors::NotEndpoint(
+                vec![3],
+                vec![1]
+            )))
+        );
+        assert_eq!(
+            h.link_direction([2], [1]),
+            Err(errors::GetError::NoLink(errors::NoLink(vec![2])))
+        );
+    }
+
     #[test]
     fn link_value() {
         let mut h = Hypergraph::<_, _, (), _>::new();
@@ -771,6 +1524,173 @@ mod tests {
         assert_eq!(h.link_value([5]).unwrap(), &Some("three"));
     }
 
+    #[test]
+    fn edge_links() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        assert_eq!(h.edge_links([2]), Ok(vec![vec![3], vec![4]]));
+
+        h.add_link([0], [2], ()).unwrap();
+        assert_eq!(h.edge_links([2]), Ok(vec![vec![3], vec![4], vec![5]]));
+        assert_eq!(
+            h.edge_links([0]),
+            Err(errors::GetError::NoEdge(errors::NoEdge(vec![0])))
+        );
+    }
+
+    #[test]
+    fn edge_incidences() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+        h.add_link([5], [2], ()).unwrap();
+        h.add_link([2], [5], ()).unwrap();
+        assert_eq!(
+            h.edge_incidences([2]),
+            Ok(vec![
+                (Direction::Incoming, vec![0]),
+                (Direction::Outgoing, vec![1]),
+                (Direction::Incoming, vec![5]),
+                (Direction::Outgoing, vec![5]),
+            ])
+        );
+        assert_eq!(
+            h.edge_incidences([0]),
+            Err(errors::GetError::NoEdge(errors::NoEdge(vec![0])))
+        );
+    }
+
+    #[test]
+    fn hypergraph_at() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.set_value("main");
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+
+        match h.hypergraph_at([]).unwrap() {
+            HypergraphEnum::Original(root) => assert_eq!(root.value(), &Some("main")),
+            HypergraphEnum::Sub(_) => panic!("the empty id refers to the original hypergraph"),
+        }
+        match h.hypergraph_at([5]).unwrap() {
+            HypergraphEnum::Original(_) => panic!("[5] refers to a subhypergraph"),
+            HypergraphEnum::Sub(sub) => assert_eq!(sub.value(), &Some("five")),
+        }
+        assert_eq!(
+            h.hypergraph_at([0]).unwrap_err(),
+            errors::GetError::NoHypergraph(errors::NoHypergraph(vec![0]))
+        );
+
+        if let HypergraphEnum::Sub(sub) = h.hypergraph_at_mut([5]).unwrap() {
+            sub.set_value("changed");
+        }
+        assert_eq!(h.hypergraph_value([5]), Ok(&Some("changed")));
+    }
+
+    #[test]
+    fn links_of_directed() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_edge([1], [0], "five").unwrap();
+
+        let all: Vec<_> = h.links_of_directed([0], None).unwrap().cloned().collect();
+        assert_eq!(&all, h.links_of([0]).unwrap());
+        let by_direction: Vec<_> = Direction::all()
+            .iter()
+            .flat_map(|&direction| h.links_of_directed([0], direction).unwrap().cloned())
+            .collect();
+        assert_eq!(
+            by_direction,
+            vec![
+                (vec![3], Direction::Outgoing),
+                (vec![7], Direction::Incoming)
+            ]
+        );
+        assert_eq!(
+            h.links_of_directed([3], None).err(),
+            Some(errors::GetError::IsLink(errors::IsLink(vec![3])))
+        );
+    }
+
+    #[test]
+    fn ids_breadth_first() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
+        h.add_link([2], [5, 0], "eleven").unwrap();
+
+        let lexicographic: Vec<_> = h.ids().collect();
+        let breadth_first: Vec<_> = h.ids_breadth_first().collect();
+        assert_eq!(lexicographic[6..8], [vec![5], vec![5, 0]]);
+        assert_eq!(breadth_first[6..8], [vec![5], vec![6]]);
+        assert_eq!(breadth_first.last(), Some(&vec![5, 4]));
+        let mut sorted = breadth_first.clone();
+        sorted.sort();
+        assert_eq!(sorted, lexicographic);
+    }
+
+    #[test]
+    fn ids_with_labels() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        let label = |id: &Vec<usize>| format!("n{:?}", id);
+
+        let labeled: Vec<_> = h.ids_with_labels(label).collect();
+        assert_eq!(labeled.len(), h.ids().count());
+        for ((id, label_id), expected_id) in labeled.iter().zip(h.ids()) {
+            assert_eq!(id, &expected_id);
+            assert_eq!(label_id, &label(id));
+        }
+        assert_eq!(labeled[1], (vec![0], "n[0]".to_string()));
+    }
+
+    #[test]
+    fn in_out_neighbors() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+
+        assert_eq!(
+            h.out_neighbors([0]).unwrap().collect::<Vec<_>>(),
+            vec![&vec![2]]
+        );
+        assert_eq!(
+            h.in_neighbors([1]).unwrap().collect::<Vec<_>>(),
+            vec![&vec![2]]
+        );
+        assert_eq!(h.in_neighbors([0]).unwrap().next(), None);
+        assert!(h.out_neighbors([3]).is_err());
+    }
+
+    #[test]
+    fn unique_neighbors() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "five").unwrap();
+
+        assert_eq!(h.neighbors(vec![0]).unwrap().count(), 2);
+        assert_eq!(h.unique_neighbors([0], Direction::Outgoing), vec![vec![2]]);
+        assert_eq!(h.unique_neighbors([2], Direction::Incoming), vec![vec![0]]);
+        assert!(h.unique_neighbors([3], Direction::Outgoing).is_empty());
+    }
+
     #[test]
     fn neighbors() {
         let mut h = Hypergraph::new();
@@ -780,20 +1700,29 @@ mod tests {
         h.add_link([0], [2], "five").unwrap();
         h.add_hypergraph("six");
 
-        assert!(h.neighbors(vec![]).next().is_none());
-        assert!(h.neighbors(vec![3]).next().is_none());
-        assert!(h.neighbors(vec![4]).next().is_none());
+        assert_eq!(
+            h.neighbors(vec![]).err(),
+            Some(errors::GetError::NoElementLinkable(
+                errors::NoElementLinkable(vec![])
+            ))
+        );
+        assert_eq!(
+            h.neighbors(vec![3]).err(),
+            Some(errors::GetError::IsLink(errors::IsLink(vec![3])))
+        );
+        assert!(h.neighbors(vec![4]).is_err());
+        assert!(h.neighbors(vec![7]).is_err());
 
-        let mut neighbors = h.neighbors(vec![0]);
+        let mut neighbors = h.neighbors(vec![0]).unwrap();
         assert_eq!(neighbors.next(), Some(&vec![2]));
         assert_eq!(neighbors.next(), Some(&vec![2]));
         assert_eq!(neighbors.next(), None);
-        let mut neighbors = h.neighbors(vec![1]);
+        let mut neighbors = h.neighbors(vec![1]).unwrap();
         assert_eq!(neighbors.next(), None);
-        let mut neighbors = h.neighbors(vec![2]);
+        let mut neighbors = h.neighbors(vec![2]).unwrap();
         assert_eq!(neighbors.next(), Some(&vec![1]));
         assert_eq!(neighbors.next(), None);
-        let mut neighbors = h.neighbors(vec![6]);
+        let mut neighbors = h.neighbors(vec![6]).unwrap();
         assert_eq!(neighbors.next(), None);
     }
 
@@ -802,7 +1731,7 @@ mod tests {
         let mut h = Hypergraph::new();
         h.add_node("zero");
         h.add_node("one");
-        h.add_edge([0], [1], "two");
+        h.add_edge([0], [1], "two").unwrap();
         h.add_link([0], [2], "three").unwrap();
         h.add_hypergraph("six");
         assert_eq!(h.next_id([]).unwrap(), vec![0]);
@@ -816,6 +1745,37 @@ mod tests {
         assert_eq!(h.next_id([0, 0]).unwrap(), vec![1]);
     }
 
+    #[test]
+    fn parent() {
+        let mut h = Hypergraph::<_, (), _>::new();
+        h.add_node("zero");
+        h.add_hypergraph("one");
+        h.add_node_in("two", [1]).unwrap();
+        assert_eq!(h.parent([1, 0]), Some(vec![1]));
+        assert_eq!(h.parent([0]), Some(vec![]));
+        assert_eq!(h.parent([]), None);
+        assert_eq!(h.parent([1, 1]), None);
+    }
+
+    #[test]
+    fn prev_id() {
+        let mut h = Hypergraph::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "three").unwrap();
+        h.add_hypergraph("six");
+        h.add_node_in("seven", [6]).unwrap();
+        assert_eq!(h.prev_id([]), None);
+        assert_eq!(h.prev_id([0]).unwrap(), Vec::<usize>::new());
+        assert_eq!(h.prev_id([1]).unwrap(), vec![0]);
+        assert_eq!(h.prev_id([6]).unwrap(), vec![5]);
+        assert_eq!(h.prev_id([6, 0]).unwrap(), vec![6]);
+        assert_eq!(h.prev_id([7]).unwrap(), vec![6, 0]);
+        assert_eq!(h.prev_id([3, 0]).unwrap(), vec![3]);
+        assert_eq!(h.prev_id([9, 9]).unwrap(), vec![6, 0]);
+    }
+
     #[test]
     fn node_value() {
         let mut h = Hypergraph::<_, ()>::new();
@@ -824,4 +1784,28 @@ mod tests {
         assert_eq!(h.node_value([0]).unwrap(), &"zero");
         assert_eq!(h.node_value([1]).unwrap(), &"one");
     }
+
+    #[test]
+    fn node_values() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        assert_eq!(
+            h.node_values(&[vec![1], vec![2], vec![0], vec![7]]),
+            vec![
+                Ok(&"one"),
+                Err(errors::GetError::NoNode(errors::NoNode(vec![2]))),
+                Ok(&"zero"),
+                Err(errors::GetError::NoNode(errors::NoNode(vec![7]))),
+            ]
+        );
+        assert_eq!(
+            h.edge_values(&[vec![2], vec![0]]),
+            vec![
+                Ok(&"two"),
+                Err(errors::GetError::NoEdge(errors::NoEdge(vec![0]))),
+            ]
+        );
+    }
 }
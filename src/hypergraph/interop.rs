@@ -0,0 +1,117 @@
+use petgraph::{graph::Graph, Directed};
+
+use crate::{Hypergraph, Main};
+
+/// How [`Hypergraph::from_petgraph`](struct.Hypergraph.html#method.from_petgraph) maps petgraph edges.
+///
+/// In both cases, the weight of a petgraph edge is the value of a link from its source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EdgeMapping {
+    /// Each petgraph edge becomes an edge, with its own links.
+    #[default]
+    Edge,
+    /// Each petgraph edge becomes a link, from its source to an edge shared by all petgraph edges
+    /// with the same source and target.
+    Link,
+}
+
+/// # Interoperability
+///
+/// Conversions from other graph libraries. Requires the corresponding feature, like `petgraph`.
+impl<N, L> Hypergraph<N, (), (), L, Main> {
+    /// Creates a hypergraph from a [`petgraph`](https://docs.rs/petgraph) graph.
+    ///
+    /// Each petgraph node becomes a node, so that the node with index `i` has id `[i]`.
+    /// Since nodes can not be linked directly, petgraph edges go through edges, as chosen by `mapping`.
+    /// The weight of each petgraph edge is carried by a link from its source
+    /// (the link to the target has no value). Petgraph edges are added in the order of their indices.
+    pub fn from_petgraph(graph: &Graph<N, L, Directed, usize>, mapping: EdgeMapping) -> Self
+    where
+        N: Clone,
+        L: Clone,
+    {
+        let mut hypergraph = Self::new();
+        hypergraph
+            .add_nodes(graph.node_weights().cloned(), [])
+            .unwrap(); // Never fails since the top level is a hypergraph
+        for edge in graph.raw_edges() {
+            let source = [edge.source().index()];
+            let target = [edge.target().index()];
+            if mapping == EdgeMapping::Link {
+                if let Ok(edge_id) = hypergraph.find_edge_between(source, target, []) {
+                    hypergraph
+                        .add_link(source, edge_id, edge.weight.clone())
+                        .unwrap(); // Never fails since source is a node and edge_id refers to an edge
+                    continue;
+                }
+            }
+            let edge_id = hypergraph.add_edge(source, target, ()).unwrap(); // Never fails since source and target are nodes
+            let (link_id, _) = hypergraph.links_of(&edge_id).unwrap()[0].clone(); // Never fails since edge_id refers to an edge
+            hypergraph
+                .set_link_value(link_id, edge.weight.clone())
+                .unwrap(); // Never fails since link_id refers to a link
+        }
+        hypergraph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Petgraph graph with parallel edges from `zero` to `one`.
+    fn graph() -> Graph<&'static str, u32, Directed, usize> {
+        let mut graph = Graph::default();
+        let zero = graph.add_node("zero");
+        let one = graph.add_node("one");
+        let two = graph.add_node("two");
+        graph.add_edge(zero, one, 1);
+        graph.add_edge(one, two, 2);
+        graph.add_edge(zero, two, 3);
+        graph.add_edge(zero, one, 4);
+        graph
+    }
+
+    #[test]
+    fn from_petgraph_edge() {
+        let graph = graph();
+        let h = Hypergraph::from_petgraph(&graph, EdgeMapping::Edge);
+        assert_eq!(h.node_count(), graph.node_count());
+        assert_eq!(h.edge_count(), graph.edge_count());
+        assert_eq!(h.link_count(), 2 * graph.edge_count());
+        for index in graph.node_indices() {
+            assert_eq!(h.node_value([index.index()]), Ok(&graph[index]));
+        }
+        for edge in graph.raw_edges() {
+            let source = [edge.source().index()];
+            let target = [edge.target().index()];
+            let edge_id = h.find_edge_between(source, target, []).unwrap();
+            assert_eq!(h.multiplicity(edge_id, target, []), 1);
+        }
+        assert_eq!(h.link_value([4]), Ok(&Some(1)));
+        assert_eq!(h.link_value([13]), Ok(&Some(4)));
+        assert_eq!(h.validate(), Ok(()));
+    }
+
+    #[test]
+    fn from_petgraph_link() {
+        let graph = graph();
+        let h = Hypergraph::from_petgraph(&graph, EdgeMapping::Link);
+        assert_eq!(h.node_count(), graph.node_count());
+        assert_eq!(h.edge_count(), 3);
+        assert_eq!(h.link_count(), graph.edge_count() + 3);
+        for index in graph.node_indices() {
+            assert_eq!(h.node_value([index.index()]), Ok(&graph[index]));
+        }
+        for edge in graph.raw_edges() {
+            let source = [edge.source().index()];
+            let target = [edge.target().index()];
+            let edge_id = h.find_edge_between(source, target, []).unwrap();
+            assert!(h.find_link_id(source, &edge_id, &edge.weight, []).is_ok());
+        }
+        let edge_id = h.find_edge_between([0], [1], []).unwrap();
+        assert_eq!(h.multiplicity([0], &edge_id, []), 2);
+        assert_eq!(h.multiplicity(&edge_id, [1], []), 1);
+        assert_eq!(h.validate(), Ok(()));
+    }
+}
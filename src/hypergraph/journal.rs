@@ -0,0 +1,353 @@
+use core::ops::Deref;
+
+use super::removal::Removal;
+use crate::{
+    elements::{ElementExt, ElementValue},
+    errors, Hypergraph, Main,
+};
+
+/// Operation recorded in the journal, stored in the form that reverts (or replays) it.
+#[derive(Clone, Debug)]
+enum Operation<N, E, H, L> {
+    /// `element` was added in `location` with id `id`.
+    Add {
+        element: ElementExt<N, E, H, L, Vec<usize>>,
+        location: Vec<usize>,
+        id: Vec<usize>,
+    },
+    /// The element `id` was removed, and `removal` restores it, if present.
+    ///
+    /// Once undone, `removal` is `None` and the element is removed again on redo.
+    Remove {
+        id: Vec<usize>,
+        removal: Option<Removal<N, E, H, L>>,
+    },
+    /// The value to swap with the value of element `id`.
+    Set {
+        id: Vec<usize>,
+        value: ElementValue<N, E, H, L>,
+    },
+}
+
+/// Hypergraph that records a reversible log of its modifications.
+///
+/// Every successful modification through this wrapper can be reverted with [`undo`](#method.undo)
+/// and replayed with [`redo`](#method.redo). Read access to the underlying hypergraph is given by `Deref`.
+/// Requires the `journal` feature.
+///
+/// # Remarks
+///
+/// Adding an element or setting a value is recorded by its inverse.
+/// Removing an element stores a copy of everything the removal can modify: the element itself
+/// (with all its contents, if it is a hypergraph), its links, the edges they may take along,
+/// and the list of links of the other endpoints. Its cost grows with the removed element
+/// and its neighborhood, not with the whole hypergraph.
+#[derive(Clone, Debug)]
+pub struct Journaled<N, E, H, L> {
+    hypergraph: Hypergraph<N, E, H, L, Main>,
+    undo: Vec<Operation<N, E, H, L>>,
+    redo: Vec<Operation<N, E, H, L>>,
+}
+
+impl<N, E, H, L> Default for Journaled<N, E, H, L> {
+    fn default() -> Self {
+        Self::new(Hypergraph::new())
+    }
+}
+
+impl<N, E, H, L> Deref for Journaled<N, E, H, L> {
+    type Target = Hypergraph<N, E, H, L, Main>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.hypergraph
+    }
+}
+
+impl<N, E, H, L> Journaled<N, E, H, L> {
+    /// Starts recording modifications of `hypergraph`, with an empty history.
+    pub fn new(hypergraph: Hypergraph<N, E, H, L, Main>) -> Self {
+        Journaled {
+            hypergraph,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if there is an operation to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an operation to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all recorded operations.
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Returns the underlying hypergraph, dropping the history.
+    pub fn into_inner(self) -> Hypergraph<N, E, H, L, Main> {
+        self.hypergraph
+    }
+
+    /// Records `operation` as the last one, discarding operations that could be redone.
+    fn record(&mut self, operation: Operation<N, E, H, L>) {
+        self.undo.push(operation);
+        self.redo.clear();
+    }
+}
+
+/// # Journal
+///
+/// Modifications that can be reverted.
+impl<N, E, H, L> Journaled<N, E, H, L>
+where
+    N: Clone,
+    E: Clone,
+    H: Clone,
+    L: Clone,
+{
+    /// Same as [`Hypergraph::add_element_in`](struct.Hypergraph.html#method.add_element_in), but recorded.
+    pub fn add_element_in(
+        &mut self,
+        element: ElementExt<N, E, H, L, Vec<usize>>,
+        location: impl AsRef<[usize]>,
+    ) -> Result<Vec<usize>, errors::AddError> {
+        let location = location.as_ref().to_vec();
+        let id = self.hypergraph.add_element_in(element.clone(), &location)?;
+        self.record(Operation::Add {
+            element,
+            location,
+            id: id.clone(),
+        });
+        Ok(id)
+    }
+
+    /// Same as [`Hypergraph::add_edge_in`](struct.Hypergraph.html#method.add_edge_in), but recorded.
+    pub fn add_edge_in(
+        &mut self,
+        source: impl AsRef<[usize]>,
+        target: impl AsRef<[usize]>,
+        value: E,
+        location: impl AsRef<[usize]>,
+    ) -> Result<Vec<usize>, errors::AddError> {
+        let element = ElementExt::Edge {
+            source: source.as_ref().to_vec(),
+            target: target.as_ref().to_vec(),
+            value,
+        };
+        self.add_element_in(element, location)
+    }
+
+    /// Same as [`Hypergraph::add_hypergraph_in`](struct.Hypergraph.html#method.add_hypergraph_in), but recorded.
+    pub fn add_hypergraph_in(
+        &mut self,
+        value: impl Into<Option<H>>,
+        location: impl AsRef<[usize]>,
+    ) -> Result<Vec<usize>, errors::AddError> {
+        let element = ElementExt::Hypergraph {
+            value: value.into(),
+        };
+        self.add_element_in(element, location)
+    }
+
+    /// Same as [`Hypergraph::add_link_in`](struct.Hypergraph.html#method.add_link_in), but recorded.
+    pub fn add_link_in(
+        &mut self,
+        source: impl AsRef<[usize]>,
+        target: impl AsRef<[usize]>,
+        value: impl Into<Option<L>>,
+        location: impl AsRef<[usize]>,
+    ) -> Result<Vec<usize>, errors::AddError> {
+        let element = ElementExt::Link {
+            source: source.as_ref().to_vec(),
+            target: target.as_ref().to_vec(),
+            value: value.into(),
+        };
+        self.add_element_in(element, location)
+    }
+
+    /// Same as [`Hypergraph::add_node_in`](struct.Hypergraph.html#method.add_node_in), but recorded.
+    pub fn add_node_in(
+        &mut self,
+        value: N,
+        location: impl AsRef<[usize]>,
+    ) -> Result<Vec<usize>, errors::AddError> {
+        self.add_element_in(ElementExt::Node { value }, location)
+    }
+
+    /// Same as [`Hypergraph::remove`](struct.Hypergraph.html#method.remove), but recorded.
+    pub fn remove(
+        &mut self,
+        id: impl AsRef<[usize]>,
+    ) -> Result<ElementValue<N, E, H, L>, errors::RemoveError> {
+        let id = id.as_ref().to_vec();
+        let (value, removal) = self.hypergraph.remove_recorded(&id)?;
+        self.record(Operation::Remove {
+            id,
+            removal: Some(removal),
+        });
+        Ok(value)
+    }
+
+    /// Same as [`Hypergraph::set_element_value`](struct.Hypergraph.html#method.set_element_value), but recorded.
+    pub fn set_element_value(
+        &mut self,
+        id: impl AsRef<[usize]>,
+        new_value: ElementValue<N, E, H, L>,
+    ) -> Result<ElementValue<N, E, H, L>, errors::SetError> {
+        let id = id.as_ref().to_vec();
+        let old_value = self.hypergraph.set_element_value(&id, new_value)?;
+        self.record(Operation::Set {
+            id,
+            value: old_value.clone(),
+        });
+        Ok(old_value)
+    }
+
+    /// Reverts the last recorded operation.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some(operation) => {
+                let inverse = self.revert(operation);
+                self.redo.push(inverse);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replays the last reverted operation.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(operation) => {
+                let inverse = self.revert(operation);
+                self.undo.push(inverse);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Applies the inverse of `operation`, returning the operation that reverts it back.
+    ///
+    /// Undone additions are represented by `Add` in the redo stack, and re-added on redo.
+    fn revert(&mut self, operation: Operation<N, E, H, L>) -> Operation<N, E, H, L> {
+        match operation {
+            Operation::Add {
+                element,
+                location,
+                id,
+            } => {
+                if self.hypergraph.contains(&id) {
+                    self.hypergraph.remove_last_added(&id);
+                } else {
+                    // Never fails since the state is the same as before the addition was undone
+                    self.hypergraph
+                        .add_element_in(element.clone(), &location)
+                        .unwrap();
+                }
+                Operation::Add {
+                    element,
+                    location,
+                    id,
+                }
+            }
+            Operation::Remove { id, removal } => match removal {
+                Some(removal) => {
+                    self.hypergraph.restore(removal);
+                    Operation::Remove { id, removal: None }
+                }
+                None => {
+                    // Never fails since the state is the same as before the removal was undone
+                    let (_, removal) = self.hypergraph.remove_recorded(&id).unwrap();
+                    Operation::Remove {
+                        id,
+                        removal: Some(removal),
+                    }
+                }
+            },
+            Operation::Set { id, value } => {
+                // Never fails since id refers to an element of the same kind
+                let value = self.hypergraph.set_element_value(&id, value).unwrap();
+                Operation::Set { id, value }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hypergraph::tests::{assert_same_elements, example};
+
+    #[test]
+    fn undo_add() {
+        let mut h = Hypergraph::<_, ()>::new();
+        h.add_node("zero");
+        let mut journaled = Journaled::new(h);
+        let before: Vec<_> = journaled.ids().collect();
+
+        assert_eq!(journaled.add_node_in("one", []), Ok(vec![1]));
+        assert!(journaled.undo());
+        assert_eq!(journaled.ids().collect::<Vec<_>>(), before);
+        assert!(!journaled.undo());
+
+        assert!(journaled.redo());
+        assert_eq!(journaled.node_value([1]), Ok(&"one"));
+        assert!(journaled.undo());
+        assert_eq!(journaled.add_node_in("two", []), Ok(vec![1]));
+        assert!(!journaled.can_redo());
+    }
+
+    #[test]
+    fn undo_remove_and_set() {
+        let mut journaled = Journaled::<_, _, (), ()>::default();
+        journaled.add_node_in("zero", []).unwrap();
+        journaled.add_node_in("one", []).unwrap();
+        journaled.add_edge_in([0], [1], "two", []).unwrap();
+        journaled
+            .set_element_value([0], ElementValue::Node { value: "changed" })
+            .unwrap();
+        journaled.remove([0]).unwrap();
+        let after: Vec<_> = journaled.ids().collect();
+        assert_eq!(after, vec![vec![], vec![1]]);
+
+        assert!(journaled.undo());
+        assert_eq!(journaled.node_value([0]), Ok(&"changed"));
+        assert_eq!(journaled.link_count(), 2);
+        assert!(journaled.undo());
+        assert_eq!(journaled.node_value([0]), Ok(&"zero"));
+
+        assert!(journaled.redo());
+        assert!(journaled.redo());
+        assert_eq!(journaled.ids().collect::<Vec<_>>(), after);
+    }
+
+    #[test]
+    fn undo_remove_cascade() {
+        let h = example();
+        let mut journaled = Journaled::new(h.clone());
+        journaled.remove([7]).unwrap();
+        journaled.remove([5]).unwrap();
+        // The edge [2] is left with one link, so it is removed too
+        journaled.remove([0]).unwrap();
+        let after = journaled.clone().into_inner();
+        assert_eq!(after.ids().collect::<Vec<_>>(), vec![vec![], vec![1]]);
+
+        while journaled.undo() {}
+        assert_same_elements(&*journaled, &h);
+        assert_eq!(journaled.validate(), Ok(()));
+
+        while journaled.redo() {}
+        assert_same_elements(&*journaled, &after);
+    }
+}
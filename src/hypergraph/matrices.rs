@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use crate::{Hypergraph, IncidenceMatrix};
+
+/// # Matrices
+///
+/// Matrix representations of the hypergraph.
+impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
+    /// Returns the ids of all nodes and edges, in all levels, and the directed incidence matrix between them.
+    ///
+    /// Rows correspond to nodes and columns to edges. Entry `(i, j)` is `-1` if the `i`-th node is a source
+    /// of the `j`-th edge, `1` if it is a target, and `0` otherwise (or if it is both).
+    /// Nodes and edges are ordered as in [`ids`](#method.ids).
+    ///
+    /// Only nodes are considered, so that hypergraphs linked to an edge are ignored.
+    pub fn incidence_matrix(&self) -> IncidenceMatrix {
+        let node_ids: Vec<_> = self.ids().filter(|id| self.contains_node(id)).collect();
+        let edge_ids: Vec<_> = self.ids().filter(|id| self.contains_edge(id)).collect();
+        let node_index: HashMap<&Vec<usize>, usize> =
+            node_ids.iter().enumerate().map(|(i, id)| (id, i)).collect();
+        let mut matrix = vec![vec![0; edge_ids.len()]; node_ids.len()];
+        for (j, edge_id) in edge_ids.iter().enumerate() {
+            let (sources, targets) = self.edge_endpoints(edge_id).unwrap(); // Never fails since edge_id refers to an edge
+            let mut incidences = vec![0; node_ids.len()];
+            for source in sources {
+                if let Some(&i) = node_index.get(source) {
+                    incidences[i] = -1;
+                }
+            }
+            for target in targets {
+                if let Some(&i) = node_index.get(target) {
+                    incidences[i] = if incidences[i] == -1 { 0 } else { 1 };
+                }
+            }
+            for (i, incidence) in incidences.into_iter().enumerate() {
+                matrix[i][j] = incidence;
+            }
+        }
+        (node_ids, edge_ids, matrix)
+    }
+
+    /// Returns the ids of all linkable elements, in all levels, and the adjacency matrix between them.
+    ///
+    /// Entry `(i, j)` is one if there is a link from the `i`-th element to the `j`-th element, and zero otherwise.
+    /// Elements are ordered as in [`ids`](#method.ids).
+    ///
+    /// See [`to_adjacency_sparse`](#method.to_adjacency_sparse) for a sparse representation.
+    pub fn to_adjacency_matrix(&self) -> (Vec<Vec<usize>>, Vec<Vec<u8>>) {
+        let (ids, entries) = self.to_adjacency_sparse();
+        let mut matrix = vec![vec![0; ids.len()]; ids.len()];
+        for (i, j) in entries {
+            matrix[i][j] = 1;
+        }
+        (ids, matrix)
+    }
+
+    /// Returns the ids of all linkable elements, in all levels, and the non-zero entries of their adjacency matrix.
+    ///
+    /// Entry `(i, j)` is present if there is a link from the `i`-th element to the `j`-th element.
+    /// Elements are ordered as in [`ids`](#method.ids) and entries are sorted, without repetitions.
+    pub fn to_adjacency_sparse(&self) -> (Vec<Vec<usize>>, Vec<(usize, usize)>) {
+        let ids: Vec<_> = self.ids().filter(|id| self.contains_linkable(id)).collect();
+        let index: HashMap<&Vec<usize>, usize> =
+            ids.iter().enumerate().map(|(i, id)| (id, i)).collect();
+        let mut entries: Vec<_> = self
+            .iter_links()
+            .map(|(_, source, target, _)| (index[&source], index[&target]))
+            .collect();
+        entries.sort_unstable();
+        entries.dedup();
+        (ids, entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn incidence_matrix() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_node("two");
+        h.add_edge([0], [1], "three").unwrap();
+        h.add_link([3], [2], "six").unwrap();
+        h.add_edge([2], [2], "seven").unwrap();
+
+        let (node_ids, edge_ids, matrix) = h.incidence_matrix();
+        assert_eq!(node_ids, vec![vec![0], vec![1], vec![2]]);
+        assert_eq!(edge_ids, vec![vec![3], vec![7]]);
+        assert_eq!(matrix, vec![vec![-1, 0], vec![1, 0], vec![1, 0]]);
+    }
+
+    #[test]
+    fn to_adjacency_matrix() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+
+        let (ids, matrix) = h.to_adjacency_matrix();
+        assert_eq!(ids, vec![vec![0], vec![1], vec![2]]);
+        assert_eq!(matrix, vec![vec![0, 0, 1], vec![0, 0, 0], vec![0, 1, 0]]);
+    }
+
+    #[test]
+    fn to_adjacency_sparse() {
+        let mut h = Hypergraph::<_, _, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "five").unwrap();
+        h.add_hypergraph("six");
+        h.add_node_in("seven", [6]).unwrap();
+        h.add_link([2], [6, 0], "eight").unwrap();
+
+        let (ids, entries) = h.to_adjacency_sparse();
+        assert_eq!(ids, vec![vec![0], vec![1], vec![2], vec![6], vec![6, 0]]);
+        assert_eq!(entries, vec![(0, 2), (2, 1), (2, 4)]);
+    }
+}
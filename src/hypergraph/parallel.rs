@@ -0,0 +1,109 @@
+use rayon::prelude::*;
+
+use crate::Hypergraph;
+
+/// # Parallel
+///
+/// Process elements in parallel, using [`rayon`](https://docs.rs/rayon). Requires the `rayon` feature.
+impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty>
+where
+    N: Sync,
+    E: Sync,
+    H: Sync,
+    L: Sync,
+{
+    /// Returns all valid ids of `self`, collected in parallel over subhypergraphs.
+    ///
+    /// Ids are sorted as in [`ids`](#method.ids).
+    pub fn par_ids(&self) -> Vec<Vec<usize>> {
+        let subhypergraphs: Vec<_> = self.iter_subhypergraphs().collect();
+        let mut ids: Vec<Vec<usize>> = subhypergraphs
+            .par_iter()
+            .flat_map_iter(|(pre_id, subhypergraph)| {
+                subhypergraph.local_ids().map(move |local_id| {
+                    let mut id = pre_id.clone();
+                    id.push(local_id);
+                    id
+                })
+            })
+            .collect();
+        ids.push(vec![]);
+        ids.extend(self.local_ids().map(|local_id| vec![local_id]));
+        ids.par_sort_unstable();
+        ids
+    }
+
+    /// Applies `f` to every node of `self`, in all levels, in parallel.
+    pub fn par_for_each_node<F>(&self, f: F)
+    where
+        F: Fn(&Vec<usize>, &N) + Sync + Send,
+    {
+        let mut nodes: Vec<_> = self
+            .raw_nodes()
+            .iter()
+            .map(|(local_id, (value, _))| (vec![*local_id], value))
+            .collect();
+        for (pre_id, subhypergraph) in self.iter_subhypergraphs() {
+            nodes.extend(
+                subhypergraph
+                    .raw_nodes()
+                    .iter()
+                    .map(|(local_id, (value, _))| {
+                        let mut id = pre_id.clone();
+                        id.push(*local_id);
+                        (id, value)
+                    }),
+            );
+        }
+        nodes.par_iter().for_each(|(id, value)| f(id, value));
+    }
+
+    /// Returns the local ids of all elements in the top level of `self`.
+    fn local_ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.raw_edges()
+            .keys()
+            .chain(self.raw_hypergraphs().keys())
+            .chain(self.raw_links().keys())
+            .chain(self.raw_nodes().keys())
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn par_ids() {
+        let mut h = Hypergraph::<_, _, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
+        h.add_link([2], [5, 0], "eleven").unwrap();
+        h.add_hypergraph_in("twelve", [5]).unwrap();
+        h.add_node_in("thirteen", [5, 5]).unwrap();
+
+        assert_eq!(h.par_ids(), h.ids().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn par_for_each_node() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node(0);
+        h.add_node(1);
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in(6, [5]).unwrap();
+
+        let visited = Mutex::new(Vec::new());
+        h.par_for_each_node(|id, value| visited.lock().unwrap().push((id.clone(), *value)));
+        let mut visited = visited.into_inner().unwrap();
+        visited.sort();
+        assert_eq!(visited, vec![(vec![0], 0), (vec![1], 1), (vec![5, 0], 6)]);
+    }
+}
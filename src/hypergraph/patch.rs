@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use super::removal::Removal;
+use crate::{elements::ElementValue, errors, Hypergraph, Main};
+
+/// Incremental update of a hypergraph, applied with [`apply_patch`](struct.Hypergraph.html#method.apply_patch).
+///
+/// Patches can be serialized, so that updates can be sent over a wire.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphPatch<N, E, H, L> {
+    /// Adds an edge from `source` to `target` in the hypergraph `location`.
+    AddEdge {
+        source: Vec<usize>,
+        target: Vec<usize>,
+        value: E,
+        location: Vec<usize>,
+    },
+    /// Adds a hypergraph in the hypergraph `location`.
+    AddHypergraph {
+        value: Option<H>,
+        location: Vec<usize>,
+    },
+    /// Adds a link from `source` to `target` in the hypergraph `location`.
+    AddLink {
+        source: Vec<usize>,
+        target: Vec<usize>,
+        value: Option<L>,
+        location: Vec<usize>,
+    },
+    /// Adds a node in the hypergraph `location`.
+    AddNode { value: N, location: Vec<usize> },
+    /// Applies all patches in order.
+    Batch(Vec<GraphPatch<N, E, H, L>>),
+    /// Removes the element `id`.
+    RemoveElement { id: Vec<usize> },
+    /// Sets the value of the element `id`.
+    SetValue {
+        id: Vec<usize>,
+        value: ElementValue<N, E, H, L>,
+    },
+}
+
+/// Inverse of an operation applied by [`apply_patch`](struct.Hypergraph.html#method.apply_patch).
+enum Undo<N, E, H, L> {
+    /// Removes the element `id`, which was the last one added.
+    Add(Vec<usize>),
+    /// Restores a removed element.
+    Remove(Removal<N, E, H, L>),
+    /// Sets back the value of the element `id`.
+    Set {
+        id: Vec<usize>,
+        value: ElementValue<N, E, H, L>,
+    },
+}
+
+/// # Patch
+///
+/// Apply incremental updates.
+impl<N, E, H, L> Hypergraph<N, E, H, L, Main>
+where
+    N: Clone,
+    E: Clone,
+    H: Clone,
+    L: Clone,
+{
+    /// Applies `patch` to `self` transactionally: if any operation fails, `self` is left unchanged.
+    ///
+    /// Operations in a [`Batch`](enum.GraphPatch.html#variant.Batch) are applied in order,
+    /// so that later operations can refer to elements added by earlier ones.
+    ///
+    /// # Errors
+    ///
+    /// The error of the first operation that fails.
+    ///
+    /// # Remarks
+    ///
+    /// Applied operations are reverted one by one, so the cost of a failure is proportional
+    /// to the elements touched by the patch, not to the size of `self`.
+    pub fn apply_patch(&mut self, patch: GraphPatch<N, E, H, L>) -> Result<(), errors::Error> {
+        let mut undo_log = Vec::new();
+        let result = self.apply_patch_logged(patch, &mut undo_log);
+        if result.is_err() {
+            for undo in undo_log.into_iter().rev() {
+                match undo {
+                    Undo::Add(id) => self.remove_last_added(&id),
+                    Undo::Remove(removal) => self.restore(removal),
+                    Undo::Set { id, value } => {
+                        // Never fails since id refers to an element of the same kind
+                        self.set_element_value(&id, value).unwrap();
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Applies `patch` to `self`, stopping at the first error.
+    ///
+    /// The inverse of each applied operation is pushed to `undo_log`.
+    fn apply_patch_logged(
+        &mut self,
+        patch: GraphPatch<N, E, H, L>,
+        undo_log: &mut Vec<Undo<N, E, H, L>>,
+    ) -> Result<(), errors::Error> {
+        match patch {
+            GraphPatch::AddEdge {
+                source,
+                target,
+                value,
+                location,
+            } => {
+                let id = self.add_edge_in(source, target, value, location)?;
+                undo_log.push(Undo::Add(id));
+            }
+            GraphPatch::AddHypergraph { value, location } => {
+                let id = self.add_hypergraph_in(value, location)?;
+                undo_log.push(Undo::Add(id));
+            }
+            GraphPatch::AddLink {
+                source,
+                target,
+                value,
+                location,
+            } => {
+                let id = self.add_link_in(source, target, value, location)?;
+                undo_log.push(Undo::Add(id));
+            }
+            GraphPatch::AddNode { value, location } => {
+                let id = self.add_node_in(value, location)?;
+                undo_log.push(Undo::Add(id));
+            }
+            GraphPatch::Batch(patches) => {
+                for patch in patches {
+                    self.apply_patch_logged(patch, undo_log)?;
+                }
+            }
+            GraphPatch::RemoveElement { id } => {
+                let (_, removal) = self.remove_recorded(&id)?;
+                undo_log.push(Undo::Remove(removal));
+            }
+            GraphPatch::SetValue { id, value } => {
+                let old_value = self.set_element_value(&id, value)?;
+                undo_log.push(Undo::Set {
+                    id,
+                    value: old_value,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hypergraph::tests::assert_same_elements;
+
+    #[test]
+    fn apply_patch() {
+        let mut expected = Hypergraph::<_, _>::new();
+        expected.add_node("zero");
+        expected.add_node("one");
+        expected.add_edge([0], [1], "two").unwrap();
+
+        let mut h = Hypergraph::<_, _>::new();
+        let patch = GraphPatch::Batch(vec![
+            GraphPatch::AddNode {
+                value: "zero",
+                location: vec![],
+            },
+            GraphPatch::AddNode {
+                value: "one",
+                location: vec![],
+            },
+            GraphPatch::AddEdge {
+                source: vec![0],
+                target: vec![1],
+                value: "two",
+                location: vec![],
+            },
+        ]);
+        assert_eq!(h.apply_patch(patch), Ok(()));
+        assert_same_elements(&h, &expected);
+    }
+
+    #[test]
+    fn apply_patch_transactional() {
+        let mut h = Hypergraph::<_, ()>::new();
+        h.add_node("zero");
+        let patch = GraphPatch::Batch(vec![
+            GraphPatch::SetValue {
+                id: vec![0],
+                value: ElementValue::Node { value: "changed" },
+            },
+            GraphPatch::AddNode {
+                value: "one",
+                location: vec![],
+            },
+            GraphPatch::RemoveElement { id: vec![5] },
+        ]);
+
+        assert_eq!(
+            h.apply_patch(patch),
+            Err(errors::Error::Remove(errors::RemoveError::NoElement(
+                errors::NoElement(vec![5])
+            )))
+        );
+        assert_eq!(h.ids().collect::<Vec<_>>(), vec![vec![], vec![0]]);
+        assert_eq!(h.node_value([0]), Ok(&"zero"));
+        assert_eq!(h.add_node("one"), vec![1]);
+    }
+
+    #[test]
+    fn apply_patch_reverts_removals() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+        h.add_edge([5], [1], "six").unwrap();
+        let before = h.clone();
+        let patch = GraphPatch::Batch(vec![
+            GraphPatch::RemoveElement { id: vec![1] },
+            GraphPatch::AddNode {
+                value: "nine",
+                location: vec![],
+            },
+            GraphPatch::RemoveElement { id: vec![0] },
+            GraphPatch::SetValue {
+                id: vec![5],
+                value: ElementValue::Edge {
+                    value: "wrong kind",
+                },
+            },
+        ]);
+
+        assert_eq!(
+            h.apply_patch(patch),
+            Err(errors::Error::Set(errors::SetError::NoEdge(
+                errors::NoEdge(vec![5])
+            )))
+        );
+        assert_same_elements(&h, &before);
+        assert_eq!(h.validate(), Ok(()));
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+
+use crate::{
+    elements::{ElementType, ElementValue},
+    errors, ElementLinks, Hypergraph, HypergraphEnum, Sub,
+};
+
+/// Entry of an element in the map of its hypergraph, as stored by `Hypergraph`.
+#[derive(Clone, Debug)]
+enum Entry<N, E, H, L> {
+    Edge(E, ElementLinks),
+    Hypergraph(Box<Hypergraph<N, E, H, L, Sub>>, ElementLinks),
+    Link(Option<L>, Vec<usize>, Vec<usize>),
+    Node(N, ElementLinks),
+}
+
+/// Entry of an element, with its id and its index in the map of its hypergraph.
+type IndexedEntry<N, E, H, L> = (Vec<usize>, usize, Entry<N, E, H, L>);
+
+/// Record of the part of a hypergraph that removing an element can modify.
+///
+/// It is obtained from [`remove_recorded`](struct.Hypergraph.html#method.remove_recorded)
+/// and reverts the removal with [`restore`](struct.Hypergraph.html#method.restore).
+/// Its size is proportional to the removed element, its links and the edges they may take along,
+/// not to the whole hypergraph.
+#[derive(Clone, Debug)]
+pub(crate) struct Removal<N, E, H, L> {
+    /// Elements that may be removed: their id, index in their map and entry.
+    entries: Vec<IndexedEntry<N, E, H, L>>,
+    /// Elements that are kept but may lose links: their id and links.
+    links: Vec<(Vec<usize>, ElementLinks)>,
+}
+
+/// Value of a removed element, with the record to restore it.
+pub(crate) type Recorded<N, E, H, L> = (ElementValue<N, E, H, L>, Removal<N, E, H, L>);
+
+/// # Removal
+///
+/// Removals that can be reverted.
+impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty>
+where
+    N: Clone,
+    E: Clone,
+    H: Clone,
+    L: Clone,
+{
+    /// Removes the element `id`, as [`remove`](#method.remove), also returning the record to restore it.
+    pub(crate) fn remove_recorded(
+        &mut self,
+        id: impl AsRef<[usize]>,
+    ) -> Result<Recorded<N, E, H, L>, errors::RemoveError> {
+        let id = id.as_ref();
+        let removal = self.removal_of(id);
+        let value = self.remove(id)?;
+        Ok((value, removal))
+    }
+
+    /// Reverts the removal recorded in `removal`, which must be the last modification of `self`.
+    ///
+    /// Elements are put back in their original position, so that iteration order is also restored.
+    pub(crate) fn restore(&mut self, removal: Removal<N, E, H, L>) {
+        let Removal { mut entries, links } = removal;
+        // Inserting in increasing order of index puts every entry back in its position
+        entries.sort_by_key(|(_, index, _)| *index);
+        for (id, index, entry) in entries {
+            if self.contains(&id) {
+                // The element was kept, but it may have lost links
+                if let Entry::Edge(_, edge_links) = entry {
+                    *self.links_of_mut(&id).unwrap() = edge_links; // Never fails since id refers to an edge
+                }
+                continue;
+            }
+            let (local_id, location) = id.split_last().unwrap(); // Never fails since id is not empty
+            let hypergraph = self.hypergraph_at_mut(location).unwrap(); // Never fails since location was kept
+            match entry {
+                Entry::Edge(value, edge_links) => {
+                    let raw_edges = hypergraph.raw_edges_mut();
+                    raw_edges.insert(*local_id, (value, edge_links));
+                    raw_edges.move_index(raw_edges.len() - 1, index);
+                }
+                Entry::Hypergraph(subhypergraph, hypergraph_links) => {
+                    let raw_hypergraphs = hypergraph.raw_hypergraphs_mut();
+                    raw_hypergraphs.insert(*local_id, (*subhypergraph, hypergraph_links));
+                    raw_hypergraphs.move_index(raw_hypergraphs.len() - 1, index);
+                }
+                Entry::Link(value, source, target) => {
+                    let raw_links = hypergraph.raw_links_mut();
+                    raw_links.insert(*local_id, (value, source, target));
+                    raw_links.move_index(raw_links.len() - 1, index);
+                }
+                Entry::Node(value, node_links) => {
+                    let raw_nodes = hypergraph.raw_nodes_mut();
+                    raw_nodes.insert(*local_id, (value, node_links));
+                    raw_nodes.move_index(raw_nodes.len() - 1, index);
+                }
+            }
+        }
+        for (id, element_links) in links {
+            *self.links_of_mut(&id).unwrap() = element_links; // Never fails since only id and edges are removed
+        }
+    }
+
+    /// Records everything that removing `id` may modify.
+    ///
+    /// This is the entry of `id` (with all its contents, if it is a hypergraph), the entries of the links
+    /// of `id` and of the edges that they may take along, and the links of all other endpoints.
+    fn removal_of(&self, id: &[usize]) -> Removal<N, E, H, L> {
+        let mut removal = Removal {
+            entries: Vec::new(),
+            links: Vec::new(),
+        };
+        if id.is_empty() || !self.contains(id) {
+            return removal;
+        }
+        let mut recorded: HashSet<Vec<usize>> = HashSet::new();
+        // Linkable elements whose links may be removed
+        let mut pending: Vec<Vec<usize>> = Vec::new();
+        removal.entries.push(self.entry(id));
+        recorded.insert(id.to_vec());
+        // Never fails since id refers to an element
+        match self.element_type(id).unwrap() {
+            ElementType::Edge | ElementType::Node => pending.push(id.to_vec()),
+            ElementType::Hypergraph => {
+                let subhypergraph = self.subhypergraph(id).unwrap(); // Never fails since id refers to a hypergraph
+                for local_id in subhypergraph.ids() {
+                    let mut inner_id = id.to_vec();
+                    inner_id.extend(local_id);
+                    if self.contains_linkable(&inner_id) {
+                        pending.push(inner_id);
+                    }
+                }
+            }
+            ElementType::Link => {
+                let (source, target) = self.link_endpoints(id).unwrap(); // Never fails since id refers to a link
+                for endpoint in [source, target] {
+                    self.record_endpoint(endpoint, &mut removal, &mut recorded, &mut pending);
+                }
+            }
+        }
+        while let Some(element) = pending.pop() {
+            // Never fails since pending elements are linkable
+            for (link_id, _) in self.links_of(&element).unwrap() {
+                // Links inside id are part of its entry
+                if link_id.starts_with(id) || !recorded.insert(link_id.clone()) {
+                    continue;
+                }
+                removal.entries.push(self.entry(link_id));
+                let (source, target) = self.link_endpoints(link_id).unwrap(); // Never fails since link_id refers to a link
+                for endpoint in [source, target] {
+                    if !endpoint.starts_with(id) {
+                        self.record_endpoint(endpoint, &mut removal, &mut recorded, &mut pending);
+                    }
+                }
+            }
+        }
+        removal
+    }
+
+    /// Records `endpoint`, which may lose a link: its entry if it is an edge, since it may be removed too,
+    /// or its links otherwise.
+    fn record_endpoint(
+        &self,
+        endpoint: &[usize],
+        removal: &mut Removal<N, E, H, L>,
+        recorded: &mut HashSet<Vec<usize>>,
+        pending: &mut Vec<Vec<usize>>,
+    ) {
+        if !recorded.insert(endpoint.to_vec()) {
+            return;
+        }
+        if self.contains_edge(endpoint) {
+            removal.entries.push(self.entry(endpoint));
+            pending.push(endpoint.to_vec());
+        } else {
+            let endpoint_links = self.links_of(endpoint).unwrap().clone(); // Never fails since endpoint is linkable
+            removal.links.push((endpoint.to_vec(), endpoint_links));
+        }
+    }
+
+    /// Returns the index of the element `id` in the map of its hypergraph, and a copy of its entry.
+    ///
+    /// # Panics
+    ///
+    /// If `id` does not refer to an element.
+    fn entry(&self, id: &[usize]) -> IndexedEntry<N, E, H, L> {
+        let local_id = id.last().expect("empty id");
+        let hypergraph = self.hypergraph_of(id).expect("id is not a valid element");
+        let (index, entry) = match self.element_type(id).expect("id is not a valid element") {
+            ElementType::Edge => {
+                let (index, _, (value, edge_links)) =
+                    hypergraph.raw_edges().get_full(local_id).unwrap(); // Never fails since id refers to an edge
+                (index, Entry::Edge(value.clone(), edge_links.clone()))
+            }
+            ElementType::Hypergraph => {
+                let (index, _, (subhypergraph, hypergraph_links)) =
+                    hypergraph.raw_hypergraphs().get_full(local_id).unwrap(); // Never fails since id refers to a hypergraph
+                (
+                    index,
+                    Entry::Hypergraph(Box::new(subhypergraph.clone()), hypergraph_links.clone()),
+                )
+            }
+            ElementType::Link => {
+                let (index, _, (value, source, target)) =
+                    hypergraph.raw_links().get_full(local_id).unwrap(); // Never fails since id refers to a link
+                (
+                    index,
+                    Entry::Link(value.clone(), source.clone(), target.clone()),
+                )
+            }
+            ElementType::Node => {
+                let (index, _, (value, node_links)) =
+                    hypergraph.raw_nodes().get_full(local_id).unwrap(); // Never fails since id refers to a node
+                (index, Entry::Node(value.clone(), node_links.clone()))
+            }
+        };
+        (id.to_vec(), index, entry)
+    }
+}
+
+impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
+    /// Removes the element `id`, which must be the last one added, so that its id is used again.
+    ///
+    /// # Panics
+    ///
+    /// If `id` does not refer to an element.
+    pub(crate) fn remove_last_added(&mut self, id: &[usize]) {
+        self.remove(id).expect("id is not a valid element");
+        // Never fails since id refers to an element
+        let (local_id, location) = id.split_last().unwrap();
+        // Never fails since location refers to the hypergraph of id
+        match self.hypergraph_at_mut(location).unwrap() {
+            HypergraphEnum::Original(h) => h.next_id = *local_id,
+            HypergraphEnum::Sub(h) => h.next_id = *local_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hypergraph::tests::{assert_same_elements, example};
+
+    #[test]
+    fn restore() {
+        let h = example();
+        for id in h.ids().skip(1) {
+            let mut copy = h.clone();
+            let (value, removal) = copy.remove_recorded(&id).unwrap();
+            assert_eq!(
+                format!("{:?}", value),
+                format!("{:?}", h.element_value(&id).unwrap())
+            );
+            assert!(!copy.contains(&id));
+            copy.restore(removal);
+            assert_same_elements(&copy, &h);
+            assert_eq!(copy.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn restore_cascade() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+        h.add_edge([1], [5], "six").unwrap();
+        h.add_link([2], [5], "nine").unwrap();
+
+        let mut copy = h.clone();
+        let (_, removal) = copy.remove_recorded([1]).unwrap();
+        assert_eq!(
+            copy.ids().collect::<Vec<_>>(),
+            vec![vec![], vec![0], vec![2], vec![3], vec![5], vec![9]]
+        );
+        copy.restore(removal);
+        assert_same_elements(&copy, &h);
+        assert_eq!(copy.add_node("ten"), vec![10]);
+    }
+
+    #[test]
+    fn remove_last_added() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        let id = h.add_edge([0], [1], "two").unwrap();
+        h.remove_last_added(&id);
+        assert_eq!(h.link_count(), 0);
+        assert_eq!(h.add_edge([1], [0], "two"), Ok(vec![2]));
+    }
+}
@@ -1,12 +1,95 @@
+use std::collections::HashMap;
+
 use crate::{
     elements::{ElementType, ElementValue},
-    errors, Direction, Hypergraph,
+    errors, Direction, Hypergraph, Main,
 };
 
+/// Source and target shared by some links, with the ids of these links.
+type LinkGroup = ((Vec<usize>, Vec<usize>), Vec<Vec<usize>>);
+
 /// # Remove
 ///
 /// Remove elements.
 impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
+    /// Removes repeated links, that is, links with the same source, target and value as a previous link.
+    ///
+    /// Returns the number of links removed.
+    ///
+    /// # Remarks
+    ///
+    /// As with [`remove_link`](#method.remove_link), an edge left with less than two links is removed.
+    pub fn dedup_links(&mut self) -> usize
+    where
+        L: PartialEq,
+    {
+        let mut removed = 0;
+        for (_, link_ids) in self.parallel_links() {
+            for (index, link_id) in link_ids.iter().enumerate() {
+                if !self.contains_link(link_id) {
+                    continue;
+                }
+                let value = self.link_value(link_id).unwrap(); // Never fails since link_id refers to a link
+                let repeated = link_ids[0..index]
+                    .iter()
+                    .any(|previous_id| self.link_value(previous_id) == Ok(value));
+                if repeated {
+                    self.remove_link(link_id).unwrap(); // Never fails since link_id refers to a link
+                    removed += 1;
+                }
+            }
+        }
+        removed
+    }
+
+    /// Merges parallel links, that is, links with the same source and target, into the first one.
+    ///
+    /// The value of each removed link is passed to `combine`, together with the value of the link kept.
+    /// Returns the number of links removed.
+    ///
+    /// # Remarks
+    ///
+    /// As with [`remove_link`](#method.remove_link), an edge left with less than two links is removed.
+    pub fn dedup_links_by<F>(&mut self, mut combine: F) -> usize
+    where
+        F: FnMut(&mut Option<L>, Option<L>),
+    {
+        let mut removed = 0;
+        for (_, link_ids) in self.parallel_links() {
+            let kept_id = &link_ids[0];
+            for link_id in &link_ids[1..] {
+                if !self.contains_link(link_id) || !self.contains_link(kept_id) {
+                    continue;
+                }
+                let value = self.remove_link(link_id).unwrap(); // Never fails since link_id refers to a link
+                removed += 1;
+                if let Ok(kept_value) = self.link_value_mut(kept_id) {
+                    combine(kept_value, value);
+                }
+            }
+        }
+        removed
+    }
+
+    /// Removes every subhypergraph, in all levels, which is empty and has no links.
+    ///
+    /// Subhypergraphs are visited bottom-up, so that a subhypergraph containing only
+    /// empty subhypergraphs is also removed. Returns the number of subhypergraphs removed.
+    pub fn prune_empty_subhypergraphs(&mut self) -> usize {
+        let subhypergraph_ids: Vec<Vec<usize>> =
+            self.iter_subhypergraphs().map(|(id, _)| id).collect();
+        let mut pruned = 0;
+        for id in subhypergraph_ids.into_iter().rev() {
+            let subhypergraph = self.subhypergraph(&id).unwrap(); // Never fails since id refers to a subhypergraph
+            let unlinked = self.links_of(&id).unwrap().is_empty(); // Never fails since id refers to a subhypergraph
+            if subhypergraph.is_empty() && unlinked {
+                self.remove_subhypergraph(&id).unwrap(); // Never fails since id refers to a subhypergraph
+                pruned += 1;
+            }
+        }
+        pruned
+    }
+
     /// Removes the element with id `id`.
     ///
     /// Returns true if the element was removed, otherwise `false`.
@@ -15,11 +98,12 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         id: impl AsRef<[usize]>,
     ) -> Result<ElementValue<N, E, H, L>, errors::RemoveError> {
         let id = id.as_ref();
-        if !self.contains(&id) {
+        if !self.contains(id) {
             Err(errors::NoElement(id.to_vec()))?
         }
+        let touched = self.debug_touched_ids(id);
         // Never fails since id refers to an element
-        let element = match self.element_type(&id).unwrap() {
+        let element = match self.element_type(id).unwrap() {
             ElementType::Edge => {
                 let value = self.remove_edge(id)?;
                 ElementValue::Edge { value }
@@ -37,12 +121,13 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
                 ElementValue::Node { value }
             }
         };
+        self.debug_assert_invariants(&touched);
         Ok(element)
     }
 
     pub fn remove_edge(&mut self, id: impl AsRef<[usize]>) -> Result<E, errors::RemoveError> {
         let id = id.as_ref();
-        if !self.contains_edge(&id) {
+        if !self.contains_edge(id) {
             Err(errors::NoEdge(id.to_vec()))?
         }
 
@@ -56,7 +141,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         let local_id = id.last().unwrap(); // Never fails since id is not empty
         let hypergraph = self.hypergraph_of_mut(id).unwrap(); // Never fails since id refers to an edge
         let raw_edges = hypergraph.raw_edges_mut();
-        let (edge_value, edge_links) = raw_edges.remove(local_id).unwrap(); // Never fails since id refers to an edge
+        let (edge_value, edge_links) = raw_edges.shift_remove(local_id).unwrap(); // Never fails since id refers to an edge
 
         // Removing the remaining two links
         // We need to remove by hand since the edge is no longer an element of the hypergraph
@@ -68,7 +153,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
                         .hypergraph_of_mut(id)
                         .unwrap() // Never fails since id refers to a link
                         .raw_links_mut()
-                        .remove(local_id)
+                        .shift_remove(local_id)
                         .unwrap(); // Never fails since id refers to a link
                     self.remove_link_from_unchecked(link_id, source_id);
                 }
@@ -77,7 +162,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
                         .hypergraph_of_mut(id)
                         .unwrap() // Never fails since id refers to a link
                         .raw_links_mut()
-                        .remove(local_id)
+                        .shift_remove(local_id)
                         .unwrap(); // Never fails since id refers to a link
                     self.remove_link_from_unchecked(link_id, target_id);
                 }
@@ -87,17 +172,47 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         Ok(edge_value)
     }
 
+    /// Removes the edge `id`, as [`remove_edge`](#method.remove_edge),
+    /// together with any of its endpoint nodes left without links.
+    ///
+    /// Endpoints that are edges or hypergraphs are never removed.
+    pub fn remove_edge_and_orphans(
+        &mut self,
+        id: impl AsRef<[usize]>,
+    ) -> Result<E, errors::RemoveError> {
+        let id = id.as_ref();
+        if !self.contains_edge(id) {
+            Err(errors::NoEdge(id.to_vec()))?
+        }
+        let mut endpoints: Vec<Vec<usize>> = self
+            .edge_incidences(id)
+            .unwrap() // Never fails since id refers to an edge
+            .into_iter()
+            .map(|(_, endpoint)| endpoint)
+            .filter(|endpoint| endpoint.as_slice() != id)
+            .collect();
+        endpoints.dedup();
+        let touched = self.debug_touched_ids(id);
+        let edge_value = self.remove_edge(id)?;
+        for endpoint in endpoints {
+            if self.contains_node(&endpoint) && self.links_of(&endpoint).unwrap().is_empty() {
+                self.remove_node(&endpoint)?;
+            }
+        }
+        self.debug_assert_invariants(&touched);
+        Ok(edge_value)
+    }
+
     pub fn remove_subhypergraph(
         &mut self,
         id: impl AsRef<[usize]>,
     ) -> Result<Option<H>, errors::RemoveError> {
         let id = id.as_ref();
-        if !self.contains_subhypergraph(&id) {
+        if !self.contains_subhypergraph(id) {
             Err(errors::NoHypergraph(id.to_vec()))?
         }
-        // Remove all links
-        let subhypergraph_links = self.links_of(id).unwrap().clone(); // Never fails since id refers to a hypergraph
-        for (link_id, _) in subhypergraph_links {
+        // Remove all links, one at a time since removing a link may remove others
+        while let Some((link_id, _)) = self.links_of(id).unwrap().first().cloned() {
             self.remove_link(link_id)?;
         }
         let id = id.to_vec();
@@ -112,7 +227,10 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             let mut gloabl_id = id.clone();
             gloabl_id.extend(local_id);
             println!("Removing id {:?}", gloabl_id);
-            self.remove(gloabl_id)?;
+            // Edges might have been removed along with their endpoints
+            if self.contains(&gloabl_id) {
+                self.remove(gloabl_id)?;
+            }
         }
         // Removing the hypergraph and receiving its value
         let local_id = id.last().unwrap(); // Never fails since id is not empty
@@ -120,7 +238,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             .hypergraph_of_mut(&id)
             .unwrap() // Never fails since id refers to a hypergraph
             .raw_hypergraphs_mut()
-            .remove(local_id)
+            .shift_remove(local_id)
             .unwrap(); // Never fails since id refers to a hypergraph
         Ok(subhypergraph.value)
     }
@@ -138,7 +256,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
 
         // Errors
         {
-            if !self.contains_link(&id) {
+            if !self.contains_link(id) {
                 Err(errors::NoLink(id.to_vec()))?
             }
             let (source_id, target_id) = self.link_endpoints(id).unwrap(); // Never fails since id refers to a link
@@ -155,13 +273,57 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
             .hypergraph_of_mut(id)
             .unwrap() // Never fails since id refers to a link
             .raw_links_mut()
-            .remove(local_id)
+            .shift_remove(local_id)
             .unwrap(); // Never fails since id refers to a link
-        self.remove_link_from_unchecked(&id, source_id);
-        self.remove_link_from_unchecked(&id, target_id);
+        self.remove_link_from_unchecked(id, source_id);
+        self.remove_link_from_unchecked(id, target_id);
         Ok(link_value)
     }
 
+    /// Returns the elements reached from `id` in `direction`, going through edges, without repetitions.
+    ///
+    /// `id` itself is never included.
+    fn bridge_ends(&self, id: &[usize], direction: Direction) -> Vec<Vec<usize>> {
+        let mut ends: Vec<Vec<usize>> = Vec::new();
+        let neighbors = self.neighbors_directed(id, direction).unwrap(); // Never fails since id refers to a node
+        for neighbor_id in neighbors {
+            let candidates = if self.contains_edge(neighbor_id) {
+                // Never fails since neighbor_id refers to an edge
+                self.neighbors_directed(neighbor_id, direction)
+                    .unwrap()
+                    .collect()
+            } else {
+                vec![neighbor_id]
+            };
+            for candidate in candidates {
+                if candidate.as_slice() != id && !ends.contains(candidate) {
+                    ends.push(candidate.clone());
+                }
+            }
+        }
+        ends
+    }
+
+    /// Returns the ids of all links, grouped by their source and target, in the order of [`ids`](#method.ids).
+    ///
+    /// Only groups with more than one link are returned.
+    fn parallel_links(&self) -> Vec<LinkGroup> {
+        let mut groups: Vec<LinkGroup> = Vec::new();
+        let mut index: HashMap<(Vec<usize>, Vec<usize>), usize> = HashMap::new();
+        for (link_id, source, target, _) in self.iter_links() {
+            let key = (source, target);
+            match index.get(&key) {
+                Some(&i) => groups[i].1.push(link_id),
+                None => {
+                    index.insert(key.clone(), groups.len());
+                    groups.push((key, vec![link_id]));
+                }
+            }
+        }
+        groups.retain(|(_, link_ids)| link_ids.len() > 1);
+        groups
+    }
+
     /// Removes the link with id `link_id` from the list of links of the element `id`.  
     ///
     /// # Panics
@@ -178,7 +340,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         let link_id = link_id.as_ref();
         let local_id = id.last().expect("empty id"); // Panics if id is empty
         let element_type = self.element_type(id).expect("id is not a valid element"); // Panics if id is not a valid element
-        let hypergraph = self.hypergraph_of_mut(&id).unwrap(); // Never fails since id refers to an element
+        let hypergraph = self.hypergraph_of_mut(id).unwrap(); // Never fails since id refers to an element
         match element_type {
             ElementType::Edge => {
                 let raw_edges = hypergraph.raw_edges_mut();
@@ -197,7 +359,7 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
                 let (_, hyperraph_links) = raw_hypergraphs.get_mut(local_id).unwrap(); // Never fails since id refers to a hypergraph
                 let link_index = hyperraph_links
                     .iter()
-                    .position(|(link_id, _)| link_id == id)
+                    .position(|(l_id, _)| link_id == l_id)
                     .expect("link_id is not among the links of id");
                 hyperraph_links.remove(link_index);
             }
@@ -218,16 +380,18 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
 
     pub fn remove_node(&mut self, id: impl AsRef<[usize]>) -> Result<N, errors::RemoveError> {
         let id = id.as_ref();
-        if !self.contains_node(&id) {
+        if !self.contains_node(id) {
             Err(errors::NoNode(id.to_vec()))?
         }
-        let local_id = id.last().unwrap(); // Never fails since id is not empty
-        for (link_id, _) in self.links_of(id).unwrap().clone() {
+        // Links are read one at a time since removing a link may remove others,
+        // for example, both links of an edge from the node to itself
+        while let Some((link_id, _)) = self.links_of(id).unwrap().first().cloned() {
             self.remove_link(link_id)?;
         }
+        let local_id = id.last().unwrap(); // Never fails since id is not empty
         let hypergraph = self.hypergraph_of_mut(id).unwrap(); // Never fails since id refers to a node
         let raw_nodes = hypergraph.raw_nodes_mut();
-        let (node_value, _) = raw_nodes.remove(local_id).unwrap(); // Never fails since id refers to a node
+        let (node_value, _) = raw_nodes.shift_remove(local_id).unwrap(); // Never fails since id refers to a node
         Ok(node_value)
     }
 
@@ -246,6 +410,69 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         self.remove(id).unwrap(); // Never fails since id refers to a valid element
         Ok(())
     }
+
+    /// Removes every element, in all levels, for which `predicate` returns `true`.
+    ///
+    /// Matching ids are collected first and then removed one at a time with [`remove`](#method.remove).
+    /// A matching element already removed along with a previous one (for example, a link of a removed node)
+    /// is skipped. Returns the number of matching elements, all of which are no longer in `self`.
+    pub fn remove_where<F>(&mut self, mut predicate: F) -> usize
+    where
+        F: FnMut(&Vec<usize>, ElementValue<&N, &E, &H, &L>) -> bool,
+    {
+        let matching: Vec<Vec<usize>> = self
+            .ids()
+            .skip(1)
+            .filter(|id| predicate(id, self.element_value(id).unwrap())) // Never fails since id refers to an element
+            .collect();
+        for id in &matching {
+            if self.contains(id) {
+                self.remove(id).unwrap(); // Never fails since id refers to an element
+            }
+        }
+        matching.len()
+    }
+}
+
+impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
+    /// Removes the node `id`, connecting each of its predecessors to each of its successors.
+    ///
+    /// Predecessors are the sources of the edges (or other linkable elements) linked to `id`,
+    /// and successors are the targets of the ones `id` is linked to.
+    /// For every pair, a new edge with value given by `edge_value` is added in the innermost
+    /// hypergraph containing both, unless one of them is `id` itself.
+    pub fn remove_node_bridging<F>(
+        &mut self,
+        id: impl AsRef<[usize]>,
+        edge_value: F,
+    ) -> Result<N, errors::RemoveError>
+    where
+        F: Fn() -> E,
+    {
+        let id = id.as_ref();
+        if !self.contains_node(id) {
+            Err(errors::NoNode(id.to_vec()))?
+        }
+        let predecessors = self.bridge_ends(id, Direction::Incoming);
+        let successors = self.bridge_ends(id, Direction::Outgoing);
+        let node_value = self.remove_node(id)?;
+        for source in &predecessors {
+            for target in &successors {
+                if !self.contains_linkable(source) || !self.contains_linkable(target) {
+                    continue;
+                }
+                let location: Vec<usize> = source[..source.len() - 1]
+                    .iter()
+                    .zip(&target[..target.len() - 1])
+                    .take_while(|(one, other)| one == other)
+                    .map(|(one, _)| *one)
+                    .collect();
+                self.add_edge_in(source, target, edge_value(), location)
+                    .unwrap(); // Never fails since both are linkable and location contains them
+            }
+        }
+        Ok(node_value)
+    }
 }
 
 #[cfg(test)]
@@ -282,6 +509,201 @@ mod tests {
         assert_eq!(h.ids().collect::<Vec<_>>(), vec![vec![], vec![1]]);
     }
 
+    #[test]
+    fn remove_edge_and_orphans() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+        h.add_edge([0], [5], "six").unwrap();
+
+        assert_eq!(h.remove_edge_and_orphans([2]), Ok("two"));
+        assert_eq!(
+            h.ids().collect::<Vec<_>>(),
+            vec![vec![], vec![0], vec![5], vec![6], vec![7], vec![8]]
+        );
+
+        assert_eq!(h.remove_edge_and_orphans([6]), Ok("six"));
+        assert_eq!(h.ids().collect::<Vec<_>>(), vec![Vec::<usize>::new()]);
+        assert_eq!(
+            h.remove_edge_and_orphans([6]),
+            Err(errors::RemoveError::NoEdge(errors::NoEdge(vec![6])))
+        );
+    }
+
+    #[test]
+    fn remove_where() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "five").unwrap();
+        h.add_link([2], [1], None).unwrap();
+        h.add_link([2], [1], "seven").unwrap();
+
+        let removed = h.remove_where(|_, value| match value {
+            ElementValue::Link { value } => value.is_none(),
+            _ => false,
+        });
+        assert_eq!(removed, 3);
+        assert_eq!(
+            h.ids().collect::<Vec<_>>(),
+            vec![vec![], vec![0], vec![1], vec![2], vec![5], vec![7]]
+        );
+        assert_eq!(h.validate(), Ok(()));
+
+        // Cascading removals are not counted
+        assert_eq!(h.remove_where(|id, _| id == &vec![0]), 1);
+        assert_eq!(h.ids().collect::<Vec<_>>(), vec![vec![], vec![1]]);
+    }
+
+    #[test]
+    fn dedup_links() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "five").unwrap();
+        h.add_link([0], [2], "five").unwrap();
+        h.add_link([0], [2], "seven").unwrap();
+
+        assert_eq!(h.dedup_links(), 1);
+        assert_eq!(
+            h.links_between([0], [2], []),
+            vec![vec![3], vec![5], vec![7]]
+        );
+        assert_eq!(h.dedup_links(), 0);
+    }
+
+    #[test]
+    fn dedup_links_by() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.set_link_value([3], 1).unwrap();
+        h.add_link([0], [2], 2).unwrap();
+        h.add_link([0], [2], 3).unwrap();
+
+        let removed = h.dedup_links_by(|kept, other| {
+            *kept = Some(kept.unwrap_or(0) + other.unwrap_or(0));
+        });
+        assert_eq!(removed, 2);
+        assert_eq!(h.links_between([0], [2], []), vec![vec![3]]);
+        assert_eq!(h.link_value([3]), Ok(&Some(6)));
+        assert_eq!(h.validate(), Ok(()));
+    }
+
+    #[test]
+    fn prune_empty_subhypergraphs() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_hypergraph("one");
+        h.add_hypergraph_in("two", [1]).unwrap();
+        h.add_hypergraph("three");
+        h.add_node_in("four", [2]).unwrap();
+        h.add_hypergraph("five");
+        h.add_edge([0], [3], "six").unwrap();
+
+        assert_eq!(h.prune_empty_subhypergraphs(), 2);
+        assert!(!h.contains([1]));
+        assert!(h.contains([2]));
+        assert!(h.contains([3]));
+        assert_eq!(h.prune_empty_subhypergraphs(), 0);
+    }
+
+    #[test]
+    fn remove_id_stability() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_node("two");
+        h.add_node("three");
+        h.add_edge([2], [3], "four").unwrap();
+        h.add_hypergraph("seven");
+        h.add_node_in("eight", [7]).unwrap();
+        h.add_node_in("nine", [7]).unwrap();
+        h.add_edge_in([7, 0], [7, 1], "ten", [7]).unwrap();
+        let mut ids: Vec<_> = h.ids().collect();
+
+        assert_eq!(h.remove([1]), Ok(ElementValue::Node { value: "one" }));
+        ids.retain(|id| id != &vec![1]);
+        assert_eq!(h.ids().collect::<Vec<_>>(), ids);
+        assert_eq!(h.node_value([0]), Ok(&"zero"));
+        assert_eq!(h.node_value([2]), Ok(&"two"));
+        assert_eq!(h.node_value([3]), Ok(&"three"));
+        assert_eq!(h.edge_value([4]), Ok(&"four"));
+
+        assert_eq!(h.remove([7, 0]), Ok(ElementValue::Node { value: "eight" }));
+        assert_eq!(
+            h.ids().collect::<Vec<_>>(),
+            vec![
+                vec![],
+                vec![0],
+                vec![2],
+                vec![3],
+                vec![4],
+                vec![5],
+                vec![6],
+                vec![7],
+                vec![7, 1]
+            ]
+        );
+        assert_eq!(
+            h.remove([7]),
+            Ok(ElementValue::Hypergraph {
+                value: Some("seven")
+            })
+        );
+        assert_eq!(h.add_node("eight"), vec![8]);
+    }
+
+    #[test]
+    fn remove_node_bridging() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_node("two");
+        h.add_edge([0], [1], "three").unwrap();
+        h.add_edge([1], [2], "six").unwrap();
+
+        assert_eq!(h.remove_node_bridging([1], || "bridge"), Ok("one"));
+        assert!(!h.contains([1]));
+        assert!(!h.contains([3]));
+        assert!(!h.contains([6]));
+        assert_eq!(h.edge_value([9]), Ok(&"bridge"));
+        assert_eq!(h.edge_endpoints([9]), Ok((vec![&vec![0]], vec![&vec![2]])));
+        assert!(h.path_exists([0], [2], Direction::Outgoing));
+        assert_eq!(
+            h.remove_node_bridging([1], || "bridge"),
+            Err(errors::RemoveError::NoNode(errors::NoNode(vec![1])))
+        );
+    }
+
+    #[test]
+    fn remove_self_edge() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_edge([0], [0], "one").unwrap();
+        h.add_hypergraph("four");
+        h.add_edge([4], [4], "five").unwrap();
+
+        assert_eq!(h.remove([0]), Ok(ElementValue::Node { value: "zero" }));
+        assert_eq!(
+            h.ids().collect::<Vec<_>>(),
+            vec![vec![], vec![4], vec![5], vec![6], vec![7]]
+        );
+        assert_eq!(
+            h.remove([4]),
+            Ok(ElementValue::Hypergraph {
+                value: Some("four")
+            })
+        );
+        assert_eq!(h.ids().collect::<Vec<_>>(), vec![Vec::<usize>::new()]);
+        assert_eq!(h.validate(), Ok(()));
+    }
+
     #[test]
     fn remove_element_by_value() {
         let mut h = Hypergraph::<_, ()>::new();
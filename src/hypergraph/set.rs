@@ -1,11 +1,219 @@
 use core::mem;
 
-use crate::{elements::ElementValue, errors, Hypergraph};
+use crate::{elements::ElementValue, errors, Direction, Hypergraph};
 
 /// # Set
 ///
 /// Set the value of elements
 impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
+    /// Sets the value of the first edge with value `old` to `new`, returning the previous value.
+    ///
+    /// # Errors
+    ///
+    /// If there is no edge with value `old`.
+    pub fn replace_edge_value(&mut self, old: &E, new: E) -> Result<E, errors::FindError>
+    where
+        E: PartialEq,
+    {
+        let id = self.find_edge_by_value(old)?;
+        Ok(self.set_edge_value(id, new).unwrap()) // Never fails since id refers to an edge
+    }
+
+    /// Sets the value of the first subhypergraph with value `old` to `new`, returning the previous value.
+    ///
+    /// # Errors
+    ///
+    /// If there is no subhypergraph with value `old`.
+    pub fn replace_hypergraph_value(
+        &mut self,
+        old: Option<&H>,
+        new: impl Into<Option<H>>,
+    ) -> Result<Option<H>, errors::FindError>
+    where
+        H: PartialEq,
+    {
+        let id = self.find_hypergraph_by_value(old)?;
+        Ok(self.set_hypergraph_value(id, new).unwrap()) // Never fails since id refers to a hypergraph
+    }
+
+    /// Sets the value of the first link with value `old` to `new`, returning the previous value.
+    ///
+    /// # Errors
+    ///
+    /// If there is no link with value `old`.
+    pub fn replace_link_value(
+        &mut self,
+        old: Option<&L>,
+        new: impl Into<Option<L>>,
+    ) -> Result<Option<L>, errors::FindError>
+    where
+        L: PartialEq,
+    {
+        let id = self.find_link_by_value(old)?;
+        Ok(self.set_link_value(id, new).unwrap()) // Never fails since id refers to a link
+    }
+
+    /// Sets the value of the first node with value `old` to `new`, returning the previous value.
+    ///
+    /// # Errors
+    ///
+    /// If there is no node with value `old`.
+    pub fn replace_node_value(&mut self, old: &N, new: N) -> Result<N, errors::FindError>
+    where
+        N: PartialEq,
+    {
+        let id = self.find_node_by_value(old)?;
+        Ok(self.set_node_value(id, new).unwrap()) // Never fails since id refers to a node
+    }
+
+    /// Rewires the edge `edge_id` so that the link coming from `old_source` comes from `new_source` instead.
+    ///
+    /// The link keeps its id and value.
+    ///
+    /// # Errors
+    ///
+    /// If `edge_id` does not refer to an edge, if there is no link from `old_source` to the edge,
+    /// or if `new_source` can not be linked to the edge.
+    /// Also, the location of the link must be coherent with `new_source`.
+    pub fn set_edge_source(
+        &mut self,
+        edge_id: impl AsRef<[usize]>,
+        old_source: impl AsRef<[usize]>,
+        new_source: impl AsRef<[usize]>,
+    ) -> Result<(), errors::AddError> {
+        self.set_edge_endpoint(
+            edge_id.as_ref(),
+            old_source.as_ref(),
+            new_source.as_ref(),
+            Direction::Incoming,
+        )
+    }
+
+    /// Rewires the edge `edge_id` so that the link going to `old_target` goes to `new_target` instead.
+    ///
+    /// The link keeps its id and value.
+    ///
+    /// # Errors
+    ///
+    /// If `edge_id` does not refer to an edge, if there is no link from the edge to `old_target`,
+    /// or if `new_target` can not be linked to the edge.
+    /// Also, the location of the link must be coherent with `new_target`.
+    pub fn set_edge_target(
+        &mut self,
+        edge_id: impl AsRef<[usize]>,
+        old_target: impl AsRef<[usize]>,
+        new_target: impl AsRef<[usize]>,
+    ) -> Result<(), errors::AddError> {
+        self.set_edge_endpoint(
+            edge_id.as_ref(),
+            old_target.as_ref(),
+            new_target.as_ref(),
+            Direction::Outgoing,
+        )
+    }
+
+    /// Rewires the link of `edge_id` in `direction` (from the perspective of the edge) with endpoint `old`
+    /// so that its endpoint is `new`.
+    fn set_edge_endpoint(
+        &mut self,
+        edge_id: &[usize],
+        old: &[usize],
+        new: &[usize],
+        direction: Direction,
+    ) -> Result<(), errors::AddError> {
+        if !self.contains_edge(edge_id) {
+            Err(errors::NoEdge(edge_id.to_vec()))?
+        }
+        // Find the link
+        let link_id = self
+            .links_of(edge_id)
+            .unwrap() // Never fails since edge_id refers to an edge
+            .iter()
+            .filter(|(_, link_direction)| link_direction == &direction)
+            .map(|(link_id, _)| link_id)
+            .find(|link_id| {
+                let (source, target) = self.link_endpoints(link_id).unwrap(); // Never fails since link_id refers to a link
+                match direction {
+                    Direction::Incoming => source == old,
+                    Direction::Outgoing => target == old,
+                }
+            })
+            .cloned();
+        let link_id = match (link_id, direction) {
+            (Some(link_id), _) => link_id,
+            (None, Direction::Incoming) => Err(errors::NotLinked(old.to_vec(), edge_id.to_vec()))?,
+            (None, Direction::Outgoing) => Err(errors::NotLinked(edge_id.to_vec(), old.to_vec()))?,
+        };
+        // Check the new endpoint
+        if new.is_empty() {
+            match direction {
+                Direction::Incoming => Err(errors::EmptySource)?,
+                Direction::Outgoing => Err(errors::EmptyTarget)?,
+            }
+        }
+        match (self.element_value(new), direction) {
+            (Err(_), Direction::Incoming) => Err(errors::AddError::NoSource(
+                errors::NoElementLinkable(new.to_vec()),
+            ))?,
+            (Err(_), Direction::Outgoing) => Err(errors::AddError::NoTarget(
+                errors::NoElementLinkable(new.to_vec()),
+            ))?,
+            (Ok(ElementValue::Link { .. }), Direction::Incoming) => {
+                Err(errors::LinkSource(new.to_vec()))?
+            }
+            (Ok(ElementValue::Link { .. }), Direction::Outgoing) => {
+                Err(errors::LinkTarget(new.to_vec()))?
+            }
+            (Ok(ElementValue::Edge { .. }), Direction::Incoming) => {
+                Err(errors::Unlinkable(new.to_vec(), edge_id.to_vec()))?
+            }
+            (Ok(ElementValue::Edge { .. }), Direction::Outgoing) => {
+                Err(errors::Unlinkable(edge_id.to_vec(), new.to_vec()))?
+            }
+            (Ok(ElementValue::Node { .. }), _) | (Ok(ElementValue::Hypergraph { .. }), _) => (),
+        }
+        // Check coherence of the location of the link
+        let location = &link_id[0..link_id.len() - 1];
+        if !new.starts_with(location) {
+            match direction {
+                Direction::Incoming => Err(errors::IncoherentLink(
+                    location.to_vec(),
+                    new.to_vec(),
+                    edge_id.to_vec(),
+                ))?,
+                Direction::Outgoing => Err(errors::IncoherentLink(
+                    location.to_vec(),
+                    edge_id.to_vec(),
+                    new.to_vec(),
+                ))?,
+            }
+        }
+
+        // Update the link
+        let local_id = link_id.last().unwrap(); // Never fails since link_id is not empty
+        let link_full = self
+            .hypergraph_of_mut(&link_id)
+            .unwrap() // Never fails since link_id refers to a link
+            .raw_links_mut()
+            .get_mut(local_id)
+            .unwrap(); // Never fails since link_id refers to a link
+        match direction {
+            Direction::Incoming => link_full.1 = new.to_vec(),
+            Direction::Outgoing => link_full.2 = new.to_vec(),
+        }
+        // Update the links of the old and new endpoints
+        let old_links = self.links_of_mut(old).unwrap(); // Never fails since old is linked to the edge
+        let link_index = old_links
+            .iter()
+            .position(|(l_id, _)| l_id == &link_id)
+            .unwrap(); // Never fails since link_id is a link of old
+        let (_, old_direction) = old_links.remove(link_index);
+        self.links_of_mut(new)
+            .unwrap() // Never fails since new is a linkable element
+            .push((link_id, old_direction));
+        Ok(())
+    }
+
     pub fn set_edge_value(
         &mut self,
         id: impl AsRef<[usize]>,
@@ -45,6 +253,111 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         }
     }
 
+    /// Moves the link `link_id` so that it goes from `new_source` to `new_target`.
+    ///
+    /// The link keeps its id and value, and the links of the old and new endpoints are updated.
+    ///
+    /// # Errors
+    ///
+    /// If `link_id` does not refer to a link, or if the link can not go from `new_source` to `new_target`,
+    /// with the same rules as [`add_link`](#method.add_link).
+    /// Also, the location of the link must be coherent with `new_source` and `new_target`,
+    /// and the edge it currently links must keep at least one source and one target.
+    pub fn set_link_endpoints(
+        &mut self,
+        link_id: impl AsRef<[usize]>,
+        new_source: impl AsRef<[usize]>,
+        new_target: impl AsRef<[usize]>,
+    ) -> Result<(), errors::AddError> {
+        let link_id = link_id.as_ref();
+        let new_source = new_source.as_ref();
+        let new_target = new_target.as_ref();
+        if !self.contains_link(link_id) {
+            Err(errors::NoLink(link_id.to_vec()))?
+        }
+        // Check the new endpoints
+        if new_source.is_empty() {
+            Err(errors::EmptySource)?
+        }
+        if new_target.is_empty() {
+            Err(errors::EmptyTarget)?
+        }
+        let source_is_edge = match self.element_value(new_source) {
+            Err(_) => Err(errors::AddError::NoSource(errors::NoElementLinkable(
+                new_source.to_vec(),
+            )))?,
+            Ok(ElementValue::Link { .. }) => Err(errors::LinkSource(new_source.to_vec()))?,
+            Ok(source_element) => source_element.is_edge(),
+        };
+        let target_is_edge = match self.element_value(new_target) {
+            Err(_) => Err(errors::AddError::NoTarget(errors::NoElementLinkable(
+                new_target.to_vec(),
+            )))?,
+            Ok(ElementValue::Link { .. }) => Err(errors::LinkTarget(new_target.to_vec()))?,
+            Ok(target_element) => target_element.is_edge(),
+        };
+        // Links go through exactly one edge
+        if source_is_edge == target_is_edge {
+            Err(errors::Unlinkable(new_source.to_vec(), new_target.to_vec()))?
+        }
+        // Check coherence of the location of the link
+        let location = &link_id[0..link_id.len() - 1];
+        if !new_source.starts_with(location) || !new_target.starts_with(location) {
+            Err(errors::IncoherentLink(
+                location.to_vec(),
+                new_source.to_vec(),
+                new_target.to_vec(),
+            ))?
+        }
+        // Check that the old edge keeps a source and a target
+        let old = self.link_endpoints(link_id).unwrap(); // Never fails since link_id is a link
+        let old_edge = if self.contains_edge(old.0) {
+            old.0.clone()
+        } else {
+            old.1.clone()
+        };
+        let (mut incoming, mut outgoing) = (0, 0);
+        let old_links = self.links_of(&old_edge).unwrap(); // Never fails since old_edge is linkable
+        for (_, direction) in old_links.iter().filter(|(l_id, _)| l_id != link_id) {
+            match direction {
+                Direction::Incoming => incoming += 1,
+                Direction::Outgoing => outgoing += 1,
+            }
+        }
+        if new_source == old_edge {
+            outgoing += 1;
+        } else if new_target == old_edge {
+            incoming += 1;
+        }
+        if incoming == 0 || outgoing == 0 {
+            Err(errors::EdgeNeedsLink(old_edge, link_id.to_vec()))?
+        }
+
+        // Update the link
+        let local_id = link_id.last().unwrap(); // Never fails since link_id is not empty
+        let link_full = self
+            .hypergraph_of_mut(link_id)
+            .unwrap() // Never fails since link_id refers to a link
+            .raw_links_mut()
+            .get_mut(local_id)
+            .unwrap(); // Never fails since link_id refers to a link
+        let old_source = mem::replace(&mut link_full.1, new_source.to_vec());
+        let old_target = mem::replace(&mut link_full.2, new_target.to_vec());
+        // Update the links of the old and new endpoints
+        for old in [old_source, old_target] {
+            self.links_of_mut(&old)
+                .unwrap() // Never fails since old is linked by link_id
+                .retain(|(l_id, _)| l_id != link_id);
+        }
+        self.links_of_mut(new_source)
+            .unwrap() // Never fails since new_source is a linkable element
+            .push((link_id.to_vec(), Direction::Outgoing));
+        self.links_of_mut(new_target)
+            .unwrap() // Never fails since new_target is a linkable element
+            .push((link_id.to_vec(), Direction::Incoming));
+        Ok(())
+    }
+
     pub fn set_link_value(
         &mut self,
         id: impl AsRef<[usize]>,
@@ -96,6 +409,20 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         mem::swap(old_value, &mut new_value);
         new_value
     }
+
+    /// Applies `f` to the value of every link, in all levels, in the order of [`ids`](#method.ids).
+    ///
+    /// Only values can be changed: the source and target of links are left untouched.
+    pub fn update_link_values<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Vec<usize>, &mut Option<L>),
+    {
+        let link_ids: Vec<Vec<usize>> = self.ids().filter(|id| self.contains_link(id)).collect();
+        for id in link_ids {
+            let value = self.link_value_mut(&id).unwrap(); // Never fails since id refers to a link
+            f(&id, value);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -111,7 +438,95 @@ mod tests {
         assert_eq!(h.edge_value([2]), Ok(&"two"));
         assert_eq!(h.set_edge_value([2], "new_two"), Ok("two"));
         assert_eq!(h.edge_value([2]), Ok(&"new_two"));
-        assert_eq!(h.neighbors([2]).next(), Some(&vec![1]));
+        assert_eq!(h.neighbors([2]).unwrap().next(), Some(&vec![1]));
+    }
+
+    #[test]
+    fn replace_values() {
+        let mut h = Hypergraph::<_, _, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.set_link_value([4], "four").unwrap();
+
+        assert_eq!(h.replace_node_value(&"one", "new_one"), Ok("one"));
+        assert_eq!(h.node_value([1]), Ok(&"new_one"));
+        assert_eq!(
+            h.replace_node_value(&"one", "other_one"),
+            Err(errors::FindError::NoNode)
+        );
+        assert_eq!(h.replace_edge_value(&"two", "new_two"), Ok("two"));
+        assert_eq!(h.edge_value([2]), Ok(&"new_two"));
+        assert_eq!(h.replace_link_value(None, "three"), Ok(None));
+        assert_eq!(h.link_value([3]), Ok(&Some("three")));
+        assert_eq!(h.replace_link_value(Some(&"four"), None), Ok(Some("four")));
+        assert_eq!(h.link_value([4]), Ok(&None));
+        assert_eq!(
+            h.replace_hypergraph_value(Some(&"five"), "new_five"),
+            Ok(Some("five"))
+        );
+        assert_eq!(h.hypergraph_value([5]), Ok(&Some("new_five")));
+    }
+
+    #[test]
+    fn set_edge_source() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+
+        assert_eq!(h.set_edge_source([2], [0], [5]), Ok(()));
+        assert_eq!(h.edge_endpoints([2]), Ok((vec![&vec![5]], vec![&vec![1]])));
+        assert_eq!(h.link_endpoints([3]), Ok((&vec![5], &vec![2])));
+        assert_eq!(
+            h.neighbors([5]).unwrap().collect::<Vec<_>>(),
+            vec![&vec![2]]
+        );
+        assert_eq!(h.links_of([0]), Ok(&vec![]));
+        assert_eq!(h.validate(), Ok(()));
+
+        assert_eq!(
+            h.set_edge_source([2], [0], [1]),
+            Err(errors::AddError::NotLinked(errors::NotLinked(
+                vec![0],
+                vec![2]
+            )))
+        );
+        assert_eq!(
+            h.set_edge_source([2], [5], [3]),
+            Err(errors::AddError::LinkSource(errors::LinkSource(vec![3])))
+        );
+    }
+
+    #[test]
+    fn set_edge_target() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+
+        assert_eq!(h.set_edge_target([2], [1], [5, 0]), Ok(()));
+        assert_eq!(
+            h.edge_endpoints([2]),
+            Ok((vec![&vec![0]], vec![&vec![5, 0]]))
+        );
+        assert_eq!(
+            h.neighbors_directed([5, 0], Direction::Incoming)
+                .unwrap()
+                .next(),
+            Some(&vec![2])
+        );
+        assert_eq!(h.validate(), Ok(()));
+        assert_eq!(
+            h.set_edge_target([2], [5, 0], [7]),
+            Err(errors::AddError::NoTarget(errors::NoElementLinkable(vec![
+                7
+            ])))
+        );
     }
 
     #[test]
@@ -123,7 +538,72 @@ mod tests {
         assert_eq!(h.hypergraph_value([0]), Ok(&Some("zero")));
         assert_eq!(h.set_hypergraph_value([0], "new_zero"), Ok(Some("zero")));
         assert_eq!(h.hypergraph_value([0]), Ok(&Some("new_zero")));
-        assert_eq!(h.neighbors([0]).next(), Some(&vec![2]));
+        assert_eq!(h.neighbors([0]).unwrap().next(), Some(&vec![2]));
+    }
+
+    #[test]
+    fn set_link_endpoints() {
+        let mut h = Hypergraph::<_, _, ()>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+        h.add_edge([1], [5], "six").unwrap();
+
+        assert_eq!(h.set_link_endpoints([4], [2], [5]), Ok(()));
+        assert_eq!(h.link_endpoints([4]), Ok((&vec![2], &vec![5])));
+        assert_eq!(h.links_of([1]), Ok(&vec![(vec![7], Direction::Outgoing)]));
+        assert_eq!(
+            h.links_of([5]),
+            Ok(&vec![
+                (vec![8], Direction::Incoming),
+                (vec![4], Direction::Incoming)
+            ])
+        );
+        assert_eq!(
+            h.links_of([2]),
+            Ok(&vec![
+                (vec![3], Direction::Incoming),
+                (vec![4], Direction::Outgoing)
+            ])
+        );
+        assert_eq!(h.validate(), Ok(()));
+        assert_eq!(
+            h.set_link_endpoints([4], [0], [1]),
+            Err(errors::AddError::Unlinkable(errors::Unlinkable(
+                vec![0],
+                vec![1]
+            )))
+        );
+        assert_eq!(
+            h.set_link_endpoints([2], [0], [6]),
+            Err(errors::AddError::NoLink(errors::NoLink(vec![2])))
+        );
+    }
+
+    #[test]
+    fn set_link_endpoints_last_link() {
+        let mut h = Hypergraph::<_, _, ()>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_edge([1], [0], "five").unwrap();
+
+        assert_eq!(
+            h.set_link_endpoints([3], [1], [5]),
+            Err(errors::AddError::EdgeNeedsLink(errors::EdgeNeedsLink(
+                vec![2],
+                vec![3]
+            )))
+        );
+        assert_eq!(h.link_endpoints([3]), Ok((&vec![0], &vec![2])));
+        assert_eq!(h.validate(), Ok(()));
+
+        // The edge keeps a source if the link changes only its target
+        h.add_link([0], [2], ()).unwrap();
+        assert_eq!(h.set_link_endpoints([3], [1], [5]), Ok(()));
+        assert_eq!(h.edge_endpoints([2]), Ok((vec![&vec![0]], vec![&vec![1]])));
+        assert_eq!(h.validate(), Ok(()));
     }
 
     #[test]
@@ -137,6 +617,33 @@ mod tests {
         assert_eq!(h.link_value([3]), Ok(&Some("new_three")));
     }
 
+    #[test]
+    fn update_link_values() {
+        let mut h = Hypergraph::<_, _, _, u32>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.set_link_value([3], 1).unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
+        h.set_link_value([5, 4], 3).unwrap();
+
+        let mut visited = Vec::new();
+        h.update_link_values(|id, value| {
+            visited.push(id.clone());
+            if let Some(weight) = value {
+                *weight *= 2;
+            }
+        });
+        assert_eq!(visited, vec![vec![3], vec![4], vec![5, 3], vec![5, 4]]);
+        assert_eq!(h.link_value([3]), Ok(&Some(2)));
+        assert_eq!(h.link_value([4]), Ok(&None));
+        assert_eq!(h.link_value([5, 4]), Ok(&Some(6)));
+        assert_eq!(h.link_endpoints([5, 4]), Ok((&vec![5, 2], &vec![5, 1])));
+    }
+
     #[test]
     fn set_node_value() {
         let mut h = Hypergraph::<_, _>::new();
@@ -146,7 +653,7 @@ mod tests {
         assert_eq!(h.node_value([0]), Ok(&"zero"));
         assert_eq!(h.set_node_value([0], "new_zero"), Ok("zero"));
         assert_eq!(h.node_value([0]), Ok(&"new_zero"));
-        assert_eq!(h.neighbors([0]).next(), Some(&vec![2]));
+        assert_eq!(h.neighbors([0]).unwrap().next(), Some(&vec![2]));
         assert_eq!(h.node_value([1]), Ok(&"one"));
     }
 }
@@ -1,9 +1,26 @@
-use crate::{Hypergraph, Main, Sub};
+use core::hash::Hash;
+use indexmap::{IndexMap, IndexSet};
+use std::collections::HashMap;
+
+use crate::{
+    elements::{Element, ElementType, ElementValue},
+    errors, Direction, Hypergraph, IdMapping, Main, OwnedElement, Sub,
+};
 
 /// # Add
 ///
 /// A graph that can be extended with further nodes and edges
 impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
+    /// Consumes `self`, returning all its elements with their ids, in all levels.
+    ///
+    /// Elements are returned in the order of [`ids`](#method.ids), starting with `self` (with id `[]`).
+    pub fn into_elements(self) -> impl Iterator<Item = OwnedElement<N, E, H, L>> {
+        let mut elements = Vec::new();
+        self.collect_elements(vec![], &mut elements);
+        elements.sort_by(|(one, _), (other, _)| one.cmp(other));
+        elements.into_iter()
+    }
+
     /// Utility method to transform into a Hypergraph of class `Sub`.
     pub fn into_sub(self) -> Hypergraph<N, E, H, L, Sub> {
         Hypergraph {
@@ -17,6 +34,172 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         }
     }
 
+    /// Returns the projection of `self` onto its nodes.
+    ///
+    /// Each node, in all levels, becomes a top-level node with the same value, in the order of [`ids`](#method.ids).
+    /// Two nodes are connected by an edge if they are both endpoints of some edge of `self`.
+    /// Each pair is connected once, from the node found first to the other one.
+    pub fn node_projection(&self) -> Hypergraph<N, (), (), (), Main>
+    where
+        N: Clone,
+    {
+        let mut projection = Hypergraph::new();
+        let mut node_of: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+        for id in self.ids().filter(|id| self.contains_node(id)) {
+            let value = self.node_value(&id).unwrap().clone(); // Never fails since id refers to a node
+            node_of.insert(id, projection.add_node(value));
+        }
+
+        let mut connected = IndexSet::new();
+        for id in self.ids().filter(|id| self.contains_edge(id)) {
+            let (sources, targets) = self.edge_endpoints(&id).unwrap(); // Never fails since id refers to an edge
+            let mut members: Vec<&Vec<usize>> = sources
+                .into_iter()
+                .chain(targets)
+                .filter_map(|endpoint| node_of.get(endpoint))
+                .collect();
+            members.sort();
+            members.dedup();
+            for (i, &member) in members.iter().enumerate() {
+                for &other in &members[i + 1..] {
+                    connected.insert((member.clone(), other.clone()));
+                }
+            }
+        }
+        for (source, target) in connected {
+            projection.add_edge(source, target, ()).unwrap(); // Never fails since both are nodes
+        }
+        projection
+    }
+
+    /// Returns the quotient graph of `self` by the equivalence relation induced by `classify`.
+    ///
+    /// Each class of nodes, in all levels, becomes a single node with value the class key.
+    /// There is an edge from one class to another (different) class if some member of the first
+    /// is linked to some member of the second, either directly or through an edge.
+    /// Classes, and edges between them, are added in the order in which they are first found.
+    pub fn quotient<F, K>(&self, classify: F) -> Hypergraph<K, (), (), (), Main>
+    where
+        F: Fn(&Vec<usize>) -> K,
+        K: Eq + Hash,
+    {
+        let mut classes: IndexMap<K, Vec<Vec<usize>>> = IndexMap::new();
+        for id in self.ids().filter(|id| self.contains_node(id)) {
+            classes.entry(classify(&id)).or_default().push(id);
+        }
+
+        let mut quotient = Hypergraph::new();
+        let mut class_of: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+        for (key, members) in classes {
+            let class_id = quotient.add_node(key);
+            for member in members {
+                class_of.insert(member, class_id.clone());
+            }
+        }
+
+        let mut connected = IndexSet::new();
+        for id in self.ids().filter(|id| self.contains_node(id)) {
+            let class_id = &class_of[&id];
+            let neighbors = self.neighbors(&id).unwrap(); // Never fails since id refers to a node
+            for neighbor_id in neighbors {
+                let targets = if self.contains_edge(neighbor_id) {
+                    // Never fails since neighbor_id refers to an edge
+                    self.neighbors(neighbor_id).unwrap().collect()
+                } else {
+                    vec![neighbor_id]
+                };
+                for target_class_id in targets.into_iter().filter_map(|id| class_of.get(id)) {
+                    if class_id != target_class_id {
+                        connected.insert((class_id.clone(), target_class_id.clone()));
+                    }
+                }
+            }
+        }
+        for (source, target) in connected {
+            quotient.add_edge(source, target, ()).unwrap(); // Never fails since classes are nodes
+        }
+        quotient
+    }
+
+    /// Returns a copy of `self` where all elements live in the top level, together with the new id of each element.
+    ///
+    /// Elements are renumbered consecutively in the order of [`ids`](#method.ids), so that the `i`-th element
+    /// (not counting `self`) gets id `[i]`. Subhypergraphs are kept as empty hypergraphs, with their value and links.
+    pub fn to_flat(&self) -> (Hypergraph<N, E, H, L, Main>, IdMapping)
+    where
+        N: Clone,
+        E: Clone,
+        H: Clone,
+        L: Clone,
+    {
+        let mapping: IdMapping = self
+            .ids()
+            .skip(1) // The root
+            .enumerate()
+            .map(|(i, id)| (id, vec![i]))
+            .collect();
+        let mut flat = Hypergraph::with_value(self.value().clone());
+        for id in self.ids().skip(1) {
+            // Never fails since id refers to an element
+            let element = match self.element_value(&id).unwrap() {
+                ElementValue::Edge { value } => Element::Edge {
+                    value: value.clone(),
+                },
+                ElementValue::Hypergraph { value } => Element::Hypergraph {
+                    value: value.cloned(),
+                },
+                ElementValue::Link { value } => {
+                    let (source, target) = self.link_endpoints(&id).unwrap(); // Never fails since id refers to a link
+                    Element::Link {
+                        value: value.cloned(),
+                        source: mapping[source].clone(),
+                        target: mapping[target].clone(),
+                    }
+                }
+                ElementValue::Node { value } => Element::Node {
+                    value: value.clone(),
+                },
+            };
+            let local_id = flat.add_local_element(element);
+            if let Ok(links) = self.links_of(&id) {
+                for (link_id, direction) in links {
+                    flat.add_local_neighbor_unchecked(
+                        local_id,
+                        (mapping[link_id].clone(), *direction),
+                    );
+                }
+            }
+        }
+        (flat, mapping)
+    }
+
+    /// Moves all elements of `self`, located at `location`, into `elements`.
+    fn collect_elements(self, location: Vec<usize>, elements: &mut Vec<OwnedElement<N, E, H, L>>) {
+        let global_id = |local_id: usize| {
+            let mut id = location.clone();
+            id.push(local_id);
+            id
+        };
+        for (local_id, (value, _)) in self.nodes {
+            elements.push((global_id(local_id), Element::Node { value }));
+        }
+        for (local_id, (value, _)) in self.edges {
+            elements.push((global_id(local_id), Element::Edge { value }));
+        }
+        for (local_id, (value, source, target)) in self.links {
+            let element = Element::Link {
+                source,
+                target,
+                value,
+            };
+            elements.push((global_id(local_id), element));
+        }
+        for (local_id, (subhypergraph, _)) in self.hypergraphs {
+            subhypergraph.collect_elements(global_id(local_id), elements);
+        }
+        elements.push((location, Element::Hypergraph { value: self.value }));
+    }
+
     /// Pre-appends `location` to all absolute ids.
     ///
     /// # Remarks
@@ -54,6 +237,278 @@ impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
         }
         self
     }
+
+    /// Removes `prefix` from all absolute ids starting with it, in all levels of `self`.
+    ///
+    /// This is the inverse of [`preappend_id`](#method.preappend_id).
+    /// Ids not starting with `prefix` are left unchanged.
+    pub(crate) fn strip_prefix(&mut self, prefix: impl AsRef<[usize]>) -> &mut Self {
+        self.replace_id_prefix(prefix, [])
+    }
+
+    /// Replaces `prefix` by `new_prefix` in all absolute ids starting with `prefix`,
+    /// in all levels of `self`.
+    ///
+    /// # Remarks
+    ///
+    /// This is useful when moving elements.
+    pub(crate) fn replace_id_prefix(
+        &mut self,
+        prefix: impl AsRef<[usize]>,
+        new_prefix: impl AsRef<[usize]>,
+    ) -> &mut Self {
+        let prefix = prefix.as_ref();
+        let new_prefix = new_prefix.as_ref();
+        let replace = |id: &mut Vec<usize>| {
+            if id.starts_with(prefix) {
+                let mut new_id = new_prefix.to_vec();
+                new_id.extend_from_slice(&id[prefix.len()..]);
+                *id = new_id;
+            }
+        };
+
+        // Nodes, Edges and Hypergraphs
+        let links_info = self
+            .nodes
+            .values_mut()
+            .map(|(_, links)| links)
+            .chain(self.edges.values_mut().map(|(_, links)| links))
+            .chain(self.hypergraphs.values_mut().map(|(_, links)| links));
+        for links in links_info {
+            for (link, _) in links {
+                replace(link);
+            }
+        }
+        // Links
+        for (_, source, target) in self.raw_links_mut().values_mut() {
+            replace(source);
+            replace(target);
+        }
+
+        // Recursive call
+        for (subhypergraph, _) in self.raw_hypergraphs_mut().values_mut() {
+            subhypergraph.replace_id_prefix(prefix, new_prefix);
+        }
+        self
+    }
+}
+
+/// # Transform
+///
+/// Change the structure of the hypergraph.
+impl<N, E, H, L> Hypergraph<N, E, H, L, Main> {
+    /// Moves the element `id` into the hypergraph `location`, where it receives a new local id.
+    ///
+    /// Returns the new id of the element. All links and linkable elements (including those nested
+    /// in a moved hypergraph) are updated accordingly.
+    ///
+    /// # Errors
+    ///
+    /// If `id` does not refer to an element or is the root, if `location` does not refer to a hypergraph,
+    /// if `location` is inside the element itself, or if after moving, a link would not be coherent
+    /// (meaning that the hypergraph where it lives must contain both its source and target).
+    pub fn move_element(
+        &mut self,
+        id: impl AsRef<[usize]>,
+        location: impl AsRef<[usize]>,
+    ) -> Result<Vec<usize>, errors::AddError> {
+        let id = id.as_ref();
+        let location = location.as_ref();
+        if id.is_empty() {
+            Err(errors::RootHypergraph)?
+        }
+        let element_type = self
+            .element_type(id)
+            .map_err(|_| errors::NoElement(id.to_vec()))?;
+        if !self.contains_hypergraph(location) {
+            Err(errors::NoHypergraph(location.to_vec()))?
+        }
+        if location.starts_with(id) {
+            Err(errors::MoveIntoItself(id.to_vec(), location.to_vec()))?
+        }
+        let mut new_id = location.to_vec();
+        new_id.push(self.hypergraph_at(location).unwrap().next_local_id()); // Never fails since location refers to a hypergraph
+
+        // Check coherence of all links after moving
+        let rename = |other: &[usize]| -> Vec<usize> {
+            if other.starts_with(id) {
+                let mut renamed = new_id.clone();
+                renamed.extend_from_slice(&other[id.len()..]);
+                renamed
+            } else {
+                other.to_vec()
+            }
+        };
+        for link_id in self.ids().filter(|link_id| self.contains_link(link_id)) {
+            let (source, target) = self.link_endpoints(&link_id).unwrap(); // Never fails since link_id refers to a link
+            let new_link_id = rename(&link_id);
+            let link_location = &new_link_id[0..new_link_id.len() - 1];
+            let new_source = rename(source);
+            let new_target = rename(target);
+            if !new_source.starts_with(link_location) || !new_target.starts_with(link_location) {
+                Err(errors::IncoherentLink(
+                    link_location.to_vec(),
+                    new_source,
+                    new_target,
+                ))?
+            }
+        }
+
+        // Move the element
+        let local_id = id.last().unwrap(); // Never fails since id is not empty
+        let hypergraph = self.hypergraph_of_mut(id).unwrap(); // Never fails since id refers to an element
+        match element_type {
+            ElementType::Edge => {
+                let (value, links) = hypergraph.raw_edges_mut().shift_remove(local_id).unwrap(); // Never fails since id refers to an edge
+                let mut hypergraph = self.hypergraph_at_mut(location).unwrap(); // Never fails since location refers to a hypergraph
+                let new_local_id = hypergraph.add_local_element(Element::Edge { value });
+                hypergraph.raw_edges_mut()[&new_local_id].1 = links;
+            }
+            ElementType::Hypergraph => {
+                let hypergraph_full = hypergraph
+                    .raw_hypergraphs_mut()
+                    .shift_remove(local_id)
+                    .unwrap(); // Never fails since id refers to a hypergraph
+                let mut hypergraph = self.hypergraph_at_mut(location).unwrap(); // Never fails since location refers to a hypergraph
+                let new_local_id =
+                    hypergraph.add_local_element(Element::Hypergraph { value: None });
+                hypergraph.raw_hypergraphs_mut()[&new_local_id] = hypergraph_full;
+            }
+            ElementType::Link => {
+                let (value, source, target) =
+                    hypergraph.raw_links_mut().shift_remove(local_id).unwrap(); // Never fails since id refers to a link
+                let mut hypergraph = self.hypergraph_at_mut(location).unwrap(); // Never fails since location refers to a hypergraph
+                hypergraph.add_local_element(Element::Link {
+                    source,
+                    target,
+                    value,
+                });
+            }
+            ElementType::Node => {
+                let (value, links) = hypergraph.raw_nodes_mut().shift_remove(local_id).unwrap(); // Never fails since id refers to a node
+                let mut hypergraph = self.hypergraph_at_mut(location).unwrap(); // Never fails since location refers to a hypergraph
+                let new_local_id = hypergraph.add_local_element(Element::Node { value });
+                hypergraph.raw_nodes_mut()[&new_local_id].1 = links;
+            }
+        }
+        self.replace_id_prefix(id, &new_id);
+
+        Ok(new_id)
+    }
+
+    /// Inserts a new node with value `node_value` inside the edge `edge_id`.
+    ///
+    /// Returns the id of the new node, which is added in the same hypergraph as the edge.
+    ///
+    /// After splitting, the edge keeps all its sources but its only target is the new node.
+    /// A new edge, with a clone of the edge value, goes from the new node to all the previous targets,
+    /// taking over the values of the previous outgoing links.
+    /// In particular, for a hyperedge with multiple targets, all of them are reached through the same new edge.
+    ///
+    /// # Errors
+    ///
+    /// If `edge_id` does not refer to an edge, or if some target of the edge is not inside the hypergraph
+    /// of the edge, where the new edge is added. In this case, `self` is not modified.
+    pub fn split_edge(
+        &mut self,
+        edge_id: impl AsRef<[usize]>,
+        node_value: N,
+    ) -> Result<Vec<usize>, errors::AddError>
+    where
+        E: Clone,
+    {
+        let edge_id = edge_id.as_ref();
+        if !self.contains_edge(edge_id) {
+            Err(errors::NoEdge(edge_id.to_vec()))?
+        }
+        let location = &edge_id[..edge_id.len() - 1];
+        let edge_value = self.edge_value(edge_id).unwrap().clone(); // Never fails since edge_id refers to an edge
+        let outgoing_links: Vec<Vec<usize>> = self
+            .links_of(edge_id)
+            .unwrap() // Never fails since edge_id refers to an edge
+            .iter()
+            .filter(|(_, direction)| *direction == Direction::Outgoing)
+            .map(|(link_id, _)| link_id.clone())
+            .collect();
+        for link_id in &outgoing_links {
+            let (_, target) = self.link_endpoints(link_id).unwrap(); // Never fails since link_id refers to a link
+            if !target.starts_with(location) {
+                Err(errors::IncoherentLink(
+                    location.to_vec(),
+                    edge_id.to_vec(),
+                    target.clone(),
+                ))?
+            }
+        }
+
+        // The edge stays alive since it is linked to the new node before losing its targets
+        let node_id = self.add_node_in(node_value, location)?;
+        self.add_link_in(edge_id, &node_id, None, location)?;
+        let mut targets = Vec::new();
+        for link_id in outgoing_links {
+            let (_, target) = self.link_endpoints(&link_id).unwrap(); // Never fails since link_id refers to a link
+            let target = target.clone();
+            let link_value = self.remove_link(&link_id).unwrap(); // Never fails since link_id refers to a link
+            targets.push((link_id[..link_id.len() - 1].to_vec(), target, link_value));
+        }
+
+        let mut targets = targets.into_iter();
+        let (_, first_target, first_value) = targets.next().unwrap(); // Never fails since edges have at least one target
+        let new_edge_id = self.add_edge_in(&node_id, &first_target, edge_value, location)?;
+        let (first_link_id, _) = self
+            .links_of(&new_edge_id)
+            .unwrap() // Never fails since new_edge_id refers to an edge
+            .iter()
+            .find(|(_, direction)| *direction == Direction::Outgoing)
+            .cloned()
+            .unwrap(); // Never fails since the new edge has a target
+        self.set_link_value(first_link_id, first_value).unwrap(); // Never fails since the link was just added
+        for (link_location, target, link_value) in targets {
+            self.add_link_in(&new_edge_id, target, link_value, link_location)?;
+        }
+        Ok(node_id)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty>
+where
+    Self: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Returns a deep copy of `self`, obtained by serializing and deserializing it.
+    ///
+    /// This is an alternative to `clone` when element values implement serde traits but not `Clone`.
+    /// Requires the `bincode` feature.
+    ///
+    /// # Panics
+    ///
+    /// If the serialization or deserialization of any value fails.
+    pub fn deep_copy(&self) -> Self {
+        let bytes = bincode::serialize(self).expect("failed to serialize the hypergraph");
+        bincode::deserialize(&bytes).expect("failed to deserialize the hypergraph")
+    }
+}
+
+impl<N, E, H, L> Hypergraph<N, E, H, L, Sub> {
+    /// Utility method to transform into a Hypergraph of class `Main`, for reuse at the top level.
+    ///
+    /// Links store absolute ids, so `location` must be the id where `self` was stored
+    /// (for example, when `self` is a clone of a subhypergraph). It is removed from all absolute ids.
+    /// Use `[]` if ids in `self` are already relative to it.
+    ///
+    /// This is the inverse of [`into_sub`](#method.into_sub).
+    pub fn into_main(mut self, location: impl AsRef<[usize]>) -> Hypergraph<N, E, H, L, Main> {
+        self.strip_prefix(location);
+        Hypergraph {
+            value: self.value,
+            edges: self.edges,
+            nodes: self.nodes,
+            links: self.links,
+            hypergraphs: self.hypergraphs,
+            next_id: self.next_id,
+            class: Main,
+        }
+    }
 }
 
 impl<N, E, H, L> From<Hypergraph<N, E, H, L, Main>> for Hypergraph<N, E, H, L, Sub> {
@@ -73,7 +528,281 @@ impl<N, E, H, L> From<Hypergraph<N, E, H, L, Main>> for Hypergraph<N, E, H, L, S
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Direction;
+    use crate::{hypergraph::tests::assert_same_elements, Direction};
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn deep_copy() {
+        use serde::{Deserialize, Serialize};
+
+        // Not `Clone`
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct OwnedPayload(String);
+
+        let mut h = Hypergraph::<OwnedPayload, OwnedPayload, OwnedPayload, OwnedPayload>::new();
+        h.add_node(OwnedPayload("zero".to_string()));
+        h.add_node(OwnedPayload("one".to_string()));
+        h.add_edge([0], [1], OwnedPayload("two".to_string()))
+            .unwrap();
+        h.add_hypergraph(OwnedPayload("five".to_string()));
+        h.add_node_in(OwnedPayload("six".to_string()), [5]).unwrap();
+        h.add_link([2], [5, 0], OwnedPayload("seven".to_string()))
+            .unwrap();
+
+        let copy = h.deep_copy();
+        assert_same_elements(&copy, &h);
+    }
+
+    #[test]
+    fn split_edge() {
+        let mut h = Hypergraph::<_, _, (), _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.set_link_value([4], "four").unwrap();
+
+        let node_id = h.split_edge([2], "split").unwrap();
+        assert_eq!(node_id, vec![5]);
+        assert_eq!(h.node_value(&node_id), Ok(&"split"));
+        assert_eq!(h.edge_endpoints([2]), Ok((vec![&vec![0]], vec![&node_id])));
+        assert_eq!(h.edge_value([7]), Ok(&"two"));
+        assert_eq!(h.edge_endpoints([7]), Ok((vec![&node_id], vec![&vec![1]])));
+        assert_eq!(h.find_link_id([7], [1], &"four", []), Ok(vec![9]));
+        assert_eq!(
+            h.neighbors_at_distance([0], 2, Direction::Outgoing),
+            vec![node_id.clone()]
+        );
+        assert_eq!(h.validate(), Ok(()));
+        assert_eq!(
+            h.split_edge([0], "error"),
+            Err(errors::AddError::NoEdge(errors::NoEdge(vec![0])))
+        );
+    }
+
+    #[test]
+    fn split_edge_nested() {
+        let mut h = Hypergraph::<_, _, (), ()>::new();
+        h.add_hypergraph(());
+        h.add_node_in("zero", [0]).unwrap();
+        h.add_node_in("one", [0]).unwrap();
+        h.add_edge_in([0, 0], [0, 1], "two", [0]).unwrap();
+        h.add_node("one");
+        h.add_link([0, 2], [1], ()).unwrap();
+        let before = h.clone();
+
+        // The target [1] is outside the hypergraph of the edge
+        assert_eq!(
+            h.split_edge([0, 2], "split"),
+            Err(errors::AddError::IncoherentLink(errors::IncoherentLink(
+                vec![0],
+                vec![0, 2],
+                vec![1]
+            )))
+        );
+        assert_same_elements(&h, &before);
+        assert_eq!(
+            h.subhypergraph([0]).unwrap().next_local_id(),
+            before.subhypergraph([0]).unwrap().next_local_id()
+        );
+
+        h.remove_link([2]).unwrap();
+        let node_id = h.split_edge([0, 2], "split").unwrap();
+        assert_eq!(node_id, vec![0, 5]);
+        assert_eq!(
+            h.edge_endpoints([0, 2]),
+            Ok((vec![&vec![0, 0]], vec![&node_id]))
+        );
+        assert_eq!(
+            h.edge_endpoints([0, 7]),
+            Ok((vec![&node_id], vec![&vec![0, 1]]))
+        );
+        assert_eq!(h.validate(), Ok(()));
+    }
+
+    #[test]
+    fn node_projection() {
+        let mut h = Hypergraph::<_, _, (), ()>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_node("two");
+        h.add_edge([0], [1], "three").unwrap();
+        h.add_link([3], [2], ()).unwrap();
+
+        let projection = h.node_projection();
+        assert_eq!(projection.node_count(), 3);
+        assert_eq!(projection.node_value([2]), Ok(&"two"));
+        assert_eq!(projection.edge_count(), 3);
+        assert!(projection.find_edge_between([0], [1], []).is_ok());
+        assert!(projection.find_edge_between([0], [2], []).is_ok());
+        assert!(projection.find_edge_between([1], [2], []).is_ok());
+    }
+
+    #[test]
+    fn quotient() {
+        let mut h = Hypergraph::<_, _>::new();
+        for value in 0..4 {
+            h.add_node(value);
+        }
+        h.add_edge([0], [1], "a").unwrap();
+        h.add_edge([1], [2], "b").unwrap();
+        h.add_edge([2], [3], "c").unwrap();
+
+        let quotient = h.quotient(|id| *h.node_value(id).unwrap() / 2);
+        assert_eq!(quotient.node_value([0]), Ok(&0));
+        assert_eq!(quotient.node_value([1]), Ok(&1));
+        assert_eq!(
+            quotient.neighbors([0]).unwrap().collect::<Vec<_>>(),
+            vec![&vec![2]]
+        );
+        assert_eq!(
+            quotient.edge_endpoints([2]),
+            Ok((vec![&vec![0]], vec![&vec![1]]))
+        );
+        assert_eq!(
+            quotient.ids().collect::<Vec<_>>(),
+            vec![vec![], vec![0], vec![1], vec![2], vec![3], vec![4]]
+        );
+    }
+
+    #[test]
+    fn to_flat() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_hypergraph("one");
+        h.add_node_in("two", [1]).unwrap();
+        h.add_node_in("three", [1]).unwrap();
+        h.add_edge_in([1, 0], [1, 1], "four", [1]).unwrap();
+        h.add_link([0], [1, 2], "seven").unwrap();
+
+        let (flat, mapping) = h.to_flat();
+        assert_eq!(flat.ids().count(), h.ids().count());
+        assert!(flat.ids().skip(1).all(|id| id.len() == 1));
+        assert_eq!(mapping[&vec![1, 2]], vec![4]);
+        assert_eq!(flat.edge_value([4]), Ok(&"four"));
+        assert_eq!(flat.node_value(&mapping[&vec![1, 1]]), Ok(&"three"));
+        assert_eq!(
+            flat.link_endpoints(&mapping[&vec![2]]),
+            Ok((&vec![0], &vec![4]))
+        );
+        assert_eq!(flat.hypergraph_value([1]), Ok(&Some("one")));
+        assert_eq!(flat.validate(), Ok(()));
+        for (id, new_id) in &mapping {
+            assert_eq!(
+                flat.links_of(new_id).ok().map(|links| links.len()),
+                h.links_of(id).ok().map(|links| links.len())
+            );
+        }
+    }
+
+    #[test]
+    fn into_elements() {
+        // Not `Clone`
+        #[derive(Debug, PartialEq)]
+        struct Owned(String);
+
+        let mut h = Hypergraph::<Owned, &str, &str, &str>::new();
+        h.add_node(Owned("zero".to_string()));
+        h.add_node(Owned("one".to_string()));
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in(Owned("six".to_string()), [5]).unwrap();
+        let ids: Vec<_> = h.ids().collect();
+
+        let elements: Vec<_> = h.into_elements().collect();
+        assert_eq!(
+            elements
+                .iter()
+                .map(|(id, _)| id.clone())
+                .collect::<Vec<_>>(),
+            ids
+        );
+        assert_eq!(elements[0], (vec![], Element::Hypergraph { value: None }));
+        assert_eq!(
+            elements[5],
+            (
+                vec![4],
+                Element::Link {
+                    source: vec![2],
+                    target: vec![1],
+                    value: None
+                }
+            )
+        );
+        let nodes: Vec<Owned> = elements
+            .into_iter()
+            .filter_map(|(_, element)| match element {
+                Element::Node { value } => Some(value),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            nodes,
+            vec![
+                Owned("zero".to_string()),
+                Owned("one".to_string()),
+                Owned("six".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn move_element() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_hypergraph_in("six", [5]).unwrap();
+
+        let new_id = h.move_element([0], [5, 0]).unwrap();
+        assert_eq!(new_id, vec![5, 0, 0]);
+        assert!(!h.contains([0]));
+        assert_eq!(h.node_value(&new_id), Ok(&"zero"));
+        assert_eq!(
+            h.links_of(&new_id).unwrap(),
+            &vec![(vec![3], Direction::Outgoing)]
+        );
+        assert_eq!(h.link_endpoints([3]), Ok((&new_id, &vec![2])));
+        assert_eq!(h.neighbors(&new_id).unwrap().next(), Some(&vec![2]));
+        assert_eq!(h.validate(), Ok(()));
+
+        // Moving a hypergraph moves everything inside
+        let new_id = h.move_element([5, 0], []).unwrap();
+        assert_eq!(new_id, vec![6]);
+        assert_eq!(h.node_value([6, 0]), Ok(&"zero"));
+        assert_eq!(h.link_endpoints([3]), Ok((&vec![6, 0], &vec![2])));
+        assert_eq!(h.validate(), Ok(()));
+    }
+
+    #[test]
+    fn move_element_error() {
+        let mut h = Hypergraph::<_, _, _>::new();
+        h.add_hypergraph("zero");
+        h.add_node_in("one", [0]).unwrap();
+        h.add_node_in("two", [0]).unwrap();
+        h.add_edge_in([0, 0], [0, 1], "three", [0]).unwrap();
+
+        assert_eq!(
+            h.move_element([0, 0], []),
+            Err(errors::AddError::IncoherentLink(errors::IncoherentLink(
+                vec![0],
+                vec![1],
+                vec![0, 2]
+            )))
+        );
+        assert_eq!(
+            h.move_element([0], [0]),
+            Err(errors::AddError::MoveIntoItself(errors::MoveIntoItself(
+                vec![0],
+                vec![0]
+            )))
+        );
+        assert_eq!(
+            h.move_element([1], []),
+            Err(errors::AddError::NoElement(errors::NoElement(vec![1])))
+        );
+        assert_eq!(h.validate(), Ok(()));
+    }
 
     #[test]
     fn preappend_id() {
@@ -105,4 +834,33 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn into_main() {
+        let mut h = Hypergraph::<_, _, _, _>::new();
+        h.add_hypergraph("zero");
+        h.add_node_in("one", [0]).unwrap();
+        h.add_node_in("two", [0]).unwrap();
+        h.add_edge_in([0, 0], [0, 1], "three", [0]).unwrap();
+        h.add_hypergraph_in("six", [0]).unwrap();
+        h.add_node_in("seven", [0, 5]).unwrap();
+        h.add_link_in([0, 2], [0, 5, 0], "eight", [0]).unwrap();
+        let sub = h.subhypergraph([0]).unwrap().clone();
+
+        let main = sub.clone().into_main([0]);
+        assert_eq!(main.validate(), Ok(()));
+        assert_eq!(
+            main.links_of([0]).unwrap(),
+            &vec![(vec![3], Direction::Outgoing)]
+        );
+        assert_eq!(
+            main.links_of([5, 0]).unwrap(),
+            &vec![(vec![6], Direction::Incoming)]
+        );
+        assert_eq!(main.link_endpoints([6]), Ok((&vec![2], &vec![5, 0])));
+
+        let mut back = main.into_sub();
+        back.preappend_id([0]);
+        assert_same_elements(&back, &sub);
+    }
 }
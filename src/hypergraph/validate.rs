@@ -0,0 +1,262 @@
+use crate::{errors::ValidationError, Direction, Hypergraph};
+
+/// # Validate
+///
+/// Check the internal consistency of the hypergraph.
+impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty> {
+    /// Checks that all invariants of the data structure hold, returning every violation found.
+    ///
+    /// In particular, it checks that
+    /// - every link endpoint exists and is linkable, and lists the link with the correct direction,
+    /// - every link listed by an element exists and has the correct direction,
+    /// - every edge has at least two links, and
+    /// - the next local id of each (sub-)hypergraph is bigger than all its local ids.
+    ///
+    /// # Remarks
+    ///
+    /// This is useful for debugging, since all methods of `Hypergraph` maintain these invariants.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        self.validate_level(&[], self, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Panics in debug builds if the next id of the hypergraph of some of `touched` is already in use,
+    /// or if some of `touched` lists a link that does not exist.
+    ///
+    /// These are the corruptions that can be introduced through raw mutable access and that would make
+    /// later operations silently override elements or fail. Only `touched` is checked, so the cost
+    /// does not grow with the hypergraph. It does nothing in release builds.
+    pub(crate) fn debug_assert_invariants(&self, touched: &[Vec<usize>]) {
+        if cfg!(debug_assertions) {
+            let mut corruptions = Vec::new();
+            for id in touched {
+                if let Some((_, location)) = id.split_last() {
+                    if let Ok(hypergraph) = self.hypergraph_at(location) {
+                        let mut next_id = location.to_vec();
+                        next_id.push(hypergraph.next_local_id());
+                        if self.contains(&next_id) {
+                            corruptions.push(ValidationError::NextIdTooSmall(location.to_vec()));
+                        }
+                    }
+                }
+                if let Ok(links) = self.links_of(id) {
+                    for (link_id, _) in links {
+                        if !self.contains_link(link_id) {
+                            corruptions.push(ValidationError::DanglingNeighbor(
+                                id.clone(),
+                                link_id.clone(),
+                            ));
+                        }
+                    }
+                }
+            }
+            debug_assert!(
+                corruptions.is_empty(),
+                "corrupted hypergraph: {:?}",
+                corruptions
+            );
+        }
+    }
+
+    /// Returns `id` and the endpoints of its links, or of itself if it is a link.
+    ///
+    /// These are the elements touched when adding or removing `id`, to be checked by
+    /// [`debug_assert_invariants`](#method.debug_assert_invariants). It is empty in release builds.
+    pub(crate) fn debug_touched_ids(&self, id: &[usize]) -> Vec<Vec<usize>> {
+        let mut touched = Vec::new();
+        if cfg!(debug_assertions) {
+            touched.push(id.to_vec());
+            let mut link_ids = vec![id.to_vec()];
+            if let Ok(links) = self.links_of(id) {
+                link_ids.extend(links.iter().map(|(link_id, _)| link_id.clone()));
+            }
+            for link_id in link_ids {
+                if let Ok((source, target)) = self.link_endpoints(link_id) {
+                    touched.push(source.clone());
+                    touched.push(target.clone());
+                }
+            }
+        }
+        touched
+    }
+
+    /// Validates the hypergraph `level`, located at `location` in `self`, and all its subhypergraphs.
+    fn validate_level<Ty2>(
+        &self,
+        location: &[usize],
+        level: &Hypergraph<N, E, H, L, Ty2>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let global_id = |local_id: &usize| {
+            let mut id = location.to_vec();
+            id.push(*local_id);
+            id
+        };
+
+        // Next id
+        let max_local_id = level
+            .raw_edges()
+            .keys()
+            .chain(level.raw_hypergraphs().keys())
+            .chain(level.raw_links().keys())
+            .chain(level.raw_nodes().keys())
+            .max();
+        if let Some(max_local_id) = max_local_id {
+            if *max_local_id >= level.next_local_id() {
+                errors.push(ValidationError::NextIdTooSmall(location.to_vec()));
+            }
+        }
+
+        // Links
+        for (local_id, (_, source, target)) in level.raw_links() {
+            let id = global_id(local_id);
+            if self.contains_linkable(source) {
+                let listed = self
+                    .links_of(source)
+                    .unwrap() // Never fails since source is linkable
+                    .contains(&(id.clone(), Direction::Outgoing));
+                if !listed {
+                    errors.push(ValidationError::MissingNeighbor(id.clone(), source.clone()));
+                }
+            } else {
+                errors.push(ValidationError::InvalidLinkSource(
+                    id.clone(),
+                    source.clone(),
+                ));
+            }
+            if self.contains_linkable(target) {
+                let listed = self
+                    .links_of(target)
+                    .unwrap() // Never fails since target is linkable
+                    .contains(&(id.clone(), Direction::Incoming));
+                if !listed {
+                    errors.push(ValidationError::MissingNeighbor(id, target.clone()));
+                }
+            } else {
+                errors.push(ValidationError::InvalidLinkTarget(id, target.clone()));
+            }
+        }
+
+        // Linkable elements
+        let linkables = level
+            .raw_edges()
+            .iter()
+            .map(|(local_id, (_, links))| (local_id, links))
+            .chain(
+                level
+                    .raw_hypergraphs()
+                    .iter()
+                    .map(|(local_id, (_, links))| (local_id, links)),
+            )
+            .chain(
+                level
+                    .raw_nodes()
+                    .iter()
+                    .map(|(local_id, (_, links))| (local_id, links)),
+            );
+        for (local_id, links) in linkables {
+            let id = global_id(local_id);
+            for (link_id, direction) in links {
+                match self.link_endpoints(link_id) {
+                    Err(_) => {
+                        errors.push(ValidationError::DanglingNeighbor(
+                            id.clone(),
+                            link_id.clone(),
+                        ));
+                    }
+                    Ok((source, target)) => {
+                        let endpoint = match direction {
+                            Direction::Outgoing => source,
+                            Direction::Incoming => target,
+                        };
+                        if endpoint != &id {
+                            errors
+                                .push(ValidationError::WrongDirection(id.clone(), link_id.clone()));
+                        }
+                    }
+                }
+            }
+        }
+        for (local_id, (_, links)) in level.raw_edges() {
+            if links.len() < 2 {
+                errors.push(ValidationError::EdgeWithFewLinks(global_id(local_id)));
+            }
+        }
+
+        // Recursive call
+        for (local_id, (subhypergraph, _)) in level.raw_hypergraphs() {
+            self.validate_level(&global_id(local_id), subhypergraph, errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hypergraph::tests::example;
+
+    #[test]
+    fn validate() {
+        assert_eq!(example().validate(), Ok(()));
+
+        let mut h = Hypergraph::<_, _, _, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_link([2], [5, 0], "seven").unwrap();
+        assert_eq!(h.validate(), Ok(()));
+
+        // Bad link target
+        h.raw_links_mut().get_mut(&4).unwrap().2 = vec![9];
+        assert_eq!(
+            h.validate(),
+            Err(vec![
+                ValidationError::InvalidLinkTarget(vec![4], vec![9]),
+                ValidationError::WrongDirection(vec![1], vec![4]),
+            ])
+        );
+    }
+
+    #[test]
+    fn validate_next_id() {
+        let mut h = Hypergraph::<_, ()>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.next_id = 1;
+        assert_eq!(
+            h.validate(),
+            Err(vec![ValidationError::NextIdTooSmall(vec![])])
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "corrupted hypergraph")]
+    fn debug_assert_invariants() {
+        let mut h = Hypergraph::<_, ()>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.links_of_mut([0])
+            .unwrap()
+            .push((vec![7], Direction::Outgoing));
+        h.add_edge([0], [1], ()).unwrap();
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "corrupted hypergraph")]
+    fn debug_assert_invariants_next_id() {
+        let mut h = Hypergraph::<_, ()>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.next_id = 0;
+        h.add_node("two");
+    }
+}
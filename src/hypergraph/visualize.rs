@@ -1,13 +1,124 @@
 use core::fmt::{Debug, Display};
-use std::{fs, io, io::Write, process, rc::Rc};
+use std::{collections::HashMap, rc::Rc};
+#[cfg(feature = "draw")]
+use std::{
+    fs, io,
+    io::Write,
+    path::{Path, PathBuf},
+    process,
+};
 
-use crate::{traits::HypergraphClass, Hypergraph};
+use crate::{elements::ElementValue, traits::HypergraphClass, Direction, Hypergraph, Sub};
+
+/// Directory where [`draw`], [`draw_and_show`] and [`show`] save their files.
+///
+/// [`draw`]: crate::Hypergraph::draw
+/// [`draw_and_show`]: crate::Hypergraph::draw_and_show
+/// [`show`]: crate::Hypergraph::show
+#[cfg(feature = "draw")]
+pub const DEFAULT_OUTPUT_DIR: &str = "target/ferret_hypergraph";
+
+/// Colors used by [`DotFormatter::color_components`](struct.DotFormatter.html#method.color_components), in order.
+const COMPONENT_COLORS: [&str; 8] = [
+    "red", "blue", "green", "orange", "purple", "brown", "magenta", "cyan",
+];
+
+/// Direction in which the ranks of the graph are laid out.
+///
+/// Corresponds to the `rankdir` attribute of Graphviz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RankDir {
+    /// Top to bottom.
+    #[default]
+    TB,
+    /// Left to right.
+    LR,
+    /// Bottom to top.
+    BT,
+    /// Right to left.
+    RL,
+}
+
+impl Display for RankDir {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = match self {
+            RankDir::TB => "TB",
+            RankDir::LR => "LR",
+            RankDir::BT => "BT",
+            RankDir::RL => "RL",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Label of an element in the dot language.
+///
+/// Closures returning a `String` produce text labels.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Label {
+    /// [HTML-like label](https://graphviz.org/doc/info/shapes.html#html), emitted between angle brackets as is.
+    Html(String),
+    /// Plain text, emitted as a quoted string after escaping it.
+    Text(String),
+}
+
+impl Label {
+    /// Returns the label as a dot language identifier, ready to be used as an atribute value.
+    fn to_dot(&self) -> String {
+        match self {
+            Label::Html(html) => format!("<{}>", html),
+            Label::Text(text) => format!("\"{}\"", escape_label(text)),
+        }
+    }
+}
+
+impl From<String> for Label {
+    fn from(text: String) -> Self {
+        Label::Text(text)
+    }
+}
+
+impl From<&str> for Label {
+    fn from(text: &str) -> Self {
+        Label::Text(text.to_string())
+    }
+}
+
+/// Label of an element, computed from its id and value.
+pub type LabelFn<T> = Rc<dyn Fn(&Vec<usize>, &T) -> Label>;
+
+/// Label of a subhypergraph, computed from its id and the whole subhypergraph.
+pub type SubhypergraphLabelFn<N, E, H, L> = LabelFn<Hypergraph<N, E, H, L, Sub>>;
+
+/// Attribute of an element, computed from its id and value. Elements mapped to `None` do not have it.
+pub type AttributeFn<N, E, H, L> =
+    Rc<dyn Fn(&Vec<usize>, ElementValue<&N, &E, &H, &L>) -> Option<String>>;
 
 pub struct DotFormatter<N, E, H, L> {
-    pub edge: Rc<dyn Fn(&Vec<usize>, &E) -> String>,
-    pub node: Rc<dyn Fn(&Vec<usize>, &N) -> String>,
-    pub hypergraph: Rc<dyn Fn(&Vec<usize>, &Option<H>) -> String>,
-    pub link: Rc<dyn Fn(&Vec<usize>, &Option<L>) -> String>,
+    pub edge: LabelFn<E>,
+    pub node: LabelFn<N>,
+    pub hypergraph: LabelFn<Option<H>>,
+    /// Label of subhypergraphs, computed from the whole subhypergraph. If set, it takes precedence over `hypergraph`,
+    /// which is still used for the main hypergraph.
+    pub hypergraph_full: Option<SubhypergraphLabelFn<N, E, H, L>>,
+    pub link: LabelFn<Option<L>>,
+    /// If `true`, edges with only their two (valueless) links, created with the edge, are drawn as a single arrow
+    /// from source to target, labeled by the edge.
+    pub collapse_edges: bool,
+    /// If `true`, subhypergraphs are drawn as a single node, without their interior,
+    /// and arrows to or from their elements are drawn to or from that node.
+    pub collapse_subhypergraphs: bool,
+    /// Direction of the layout. Only emitted if it differs from the default, `RankDir::TB`.
+    pub rankdir: RankDir,
+    /// If `true`, the elements of each section (nodes, edges, links and subhypergraphs) are emitted sorted by id,
+    /// instead of in insertion order.
+    pub sorted: bool,
+    /// Hyperlink of each element, emitted as the `URL` atribute. Elements mapped to `None` have no hyperlink.
+    pub url: Option<AttributeFn<N, E, H, L>>,
+    /// Tooltip of each element, emitted as the `tooltip` atribute. Elements mapped to `None` have no tooltip.
+    pub tooltip: Option<AttributeFn<N, E, H, L>>,
+    /// Color of each element, emitted as the `color` atribute. Elements mapped to `None` have the default color.
+    pub color: Option<AttributeFn<N, E, H, L>>,
 }
 
 impl<N, E, H, L> DotFormatter<N, E, H, L> {
@@ -73,41 +184,199 @@ impl<N, E, H, L> DotFormatter<N, E, H, L> {
         dotformatter
     }
 
+    /// Creates a new `DotFormatter` with HTML-like labels, forwarding the `Display` implementation in all fields.
+    ///
+    /// Each label is a table with the id of the element in the first row and its value in the second.
+    /// Values `None` are left blank.
+    pub fn html() -> Self
+    where
+        N: Display,
+        E: Display,
+        H: Display,
+        L: Display,
+    {
+        fn table(id: &[usize], value: Option<&dyn Display>) -> Label {
+            let value = value
+                .map(|value| escape_html(&value.to_string()))
+                .unwrap_or_default();
+            Label::Html(format!(
+                "<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD>{:?}</TD></TR><TR><TD>{}</TD></TR></TABLE>",
+                id, value
+            ))
+        }
+        let mut dotformatter = Self::new();
+        dotformatter
+            .set_edge(|id, edge| table(id, Some(edge)))
+            .set_hypergraph(|id, hypergraph_option| {
+                table(id, hypergraph_option.as_ref().map(|h| h as &dyn Display))
+            })
+            .set_link(|id, link_option| table(id, link_option.as_ref().map(|l| l as &dyn Display)))
+            .set_node(|id, node| table(id, Some(node)));
+        dotformatter
+    }
+
+    /// Creates a new `DotFormatter` that draws subhypergraphs collapsed, labeled by a summary of their contents,
+    /// and forwards the `Display` implementation in all other fields.
+    ///
+    /// The label of a subhypergraph is its value followed by the number of nodes in it (in all levels),
+    /// as in `sub (3 nodes)`.
+    pub fn summary() -> Self
+    where
+        N: Display,
+        E: Display,
+        H: Display,
+        L: Display,
+    {
+        let mut dotformatter = Self::display();
+        dotformatter
+            .set_collapse_subhypergraphs(true)
+            .set_hypergraph_full(|_, hypergraph| {
+                let nodes = hypergraph.node_count_recursive();
+                let plural = if nodes == 1 { "" } else { "s" };
+                match hypergraph.value() {
+                    Some(value) => format!("{} ({} node{})", value, nodes, plural),
+                    None => format!("({} node{})", nodes, plural),
+                }
+            });
+        dotformatter
+    }
+
     /// Creates a new `DotFormatter` with default settings.
     pub fn new() -> Self {
         Self::default()
     }
-    pub fn set_edge<F: 'static + Fn(&Vec<usize>, &E) -> String>(
-        &mut self,
-        edge_formatter: F,
-    ) -> &mut Self {
-        self.edge = Rc::new(edge_formatter);
+
+    /// Colors each node by its component in `labeling`, as given by
+    /// [`component_labeling`](struct.Hypergraph.html#method.component_labeling).
+    ///
+    /// Colors are taken from a fixed palette, cycling if there are more components than colors.
+    /// Nodes without a label have the default color.
+    pub fn color_components(&mut self, labeling: HashMap<Vec<usize>, usize>) -> &mut Self {
+        self.set_color(move |id, value| match value {
+            ElementValue::Node { .. } => labeling
+                .get(id)
+                .map(|label| COMPONENT_COLORS[label % COMPONENT_COLORS.len()].to_string()),
+            _ => None,
+        })
+    }
+
+    pub fn set_collapse_edges(&mut self, collapse_edges: bool) -> &mut Self {
+        self.collapse_edges = collapse_edges;
         self
     }
 
-    pub fn set_hypergraph<F: 'static + Fn(&Vec<usize>, &Option<H>) -> String>(
+    pub fn set_collapse_subhypergraphs(&mut self, collapse_subhypergraphs: bool) -> &mut Self {
+        self.collapse_subhypergraphs = collapse_subhypergraphs;
+        self
+    }
+
+    pub fn set_color<
+        F: 'static + Fn(&Vec<usize>, ElementValue<&N, &E, &H, &L>) -> Option<String>,
+    >(
         &mut self,
-        hypergraph_formatter: F,
+        color_formatter: F,
     ) -> &mut Self {
-        self.hypergraph = Rc::new(hypergraph_formatter);
+        self.color = Some(Rc::new(color_formatter));
+        self
+    }
+
+    pub fn set_edge<F, T>(&mut self, edge_formatter: F) -> &mut Self
+    where
+        F: 'static + Fn(&Vec<usize>, &E) -> T,
+        T: Into<Label>,
+    {
+        self.edge = Rc::new(move |id, value| edge_formatter(id, value).into());
         self
     }
 
-    pub fn set_link<F: 'static + Fn(&Vec<usize>, &Option<L>) -> String>(
+    pub fn set_hypergraph<F, T>(&mut self, hypergraph_formatter: F) -> &mut Self
+    where
+        F: 'static + Fn(&Vec<usize>, &Option<H>) -> T,
+        T: Into<Label>,
+    {
+        self.hypergraph = Rc::new(move |id, value| hypergraph_formatter(id, value).into());
+        self
+    }
+
+    pub fn set_hypergraph_full<F, T>(&mut self, hypergraph_formatter: F) -> &mut Self
+    where
+        F: 'static + Fn(&Vec<usize>, &Hypergraph<N, E, H, L, Sub>) -> T,
+        T: Into<Label>,
+    {
+        self.hypergraph_full = Some(Rc::new(move |id, hypergraph| {
+            hypergraph_formatter(id, hypergraph).into()
+        }));
+        self
+    }
+
+    pub fn set_link<F, T>(&mut self, link_formatter: F) -> &mut Self
+    where
+        F: 'static + Fn(&Vec<usize>, &Option<L>) -> T,
+        T: Into<Label>,
+    {
+        self.link = Rc::new(move |id, value| link_formatter(id, value).into());
+        self
+    }
+
+    pub fn set_node<F, T>(&mut self, node_formatter: F) -> &mut Self
+    where
+        F: 'static + Fn(&Vec<usize>, &N) -> T,
+        T: Into<Label>,
+    {
+        self.node = Rc::new(move |id, value| node_formatter(id, value).into());
+        self
+    }
+
+    pub fn set_rankdir(&mut self, rankdir: RankDir) -> &mut Self {
+        self.rankdir = rankdir;
+        self
+    }
+
+    pub fn set_sorted(&mut self, sorted: bool) -> &mut Self {
+        self.sorted = sorted;
+        self
+    }
+
+    pub fn set_tooltip<
+        F: 'static + Fn(&Vec<usize>, ElementValue<&N, &E, &H, &L>) -> Option<String>,
+    >(
         &mut self,
-        link_formatter: F,
+        tooltip_formatter: F,
     ) -> &mut Self {
-        self.link = Rc::new(link_formatter);
+        self.tooltip = Some(Rc::new(tooltip_formatter));
         self
     }
 
-    pub fn set_node<F: 'static + Fn(&Vec<usize>, &N) -> String>(
+    pub fn set_url<F: 'static + Fn(&Vec<usize>, ElementValue<&N, &E, &H, &L>) -> Option<String>>(
         &mut self,
-        node_formatter: F,
+        url_formatter: F,
     ) -> &mut Self {
-        self.node = Rc::new(node_formatter);
+        self.url = Some(Rc::new(url_formatter));
         self
     }
+
+    /// Returns the `URL`, `tooltip` and `color` atributes (already escaped) of the element `id`.
+    fn interactive_atributes(
+        &self,
+        id: &Vec<usize>,
+        value: ElementValue<&N, &E, &H, &L>,
+    ) -> Vec<(&'static str, String)> {
+        let mut atributes = Vec::new();
+        if let Some(url) = self.url.as_ref().and_then(|url| url(id, value.clone())) {
+            atributes.push(("URL", escape_label(&url)));
+        }
+        if let Some(tooltip) = self
+            .tooltip
+            .as_ref()
+            .and_then(|tooltip| tooltip(id, value.clone()))
+        {
+            atributes.push(("tooltip", escape_label(&tooltip)));
+        }
+        if let Some(color) = self.color.as_ref().and_then(|color| color(id, value)) {
+            atributes.push(("color", escape_label(&color)));
+        }
+        atributes
+    }
 }
 
 impl<N, E, H, L> Default for DotFormatter<N, E, H, L> {
@@ -116,10 +385,18 @@ impl<N, E, H, L> Default for DotFormatter<N, E, H, L> {
     /// The label of every element is its `id`.
     fn default() -> Self {
         DotFormatter {
-            edge: Rc::new(|id, _| format!("{:?}", id)),
-            node: Rc::new(|id, _| format!("{:?}", id)),
-            hypergraph: Rc::new(|id, _| format!("{:?}", id)),
-            link: Rc::new(|id, _| format!("{:?}", id)),
+            edge: Rc::new(|id, _| format!("{:?}", id).into()),
+            node: Rc::new(|id, _| format!("{:?}", id).into()),
+            hypergraph: Rc::new(|id, _| format!("{:?}", id).into()),
+            hypergraph_full: None,
+            link: Rc::new(|id, _| format!("{:?}", id).into()),
+            collapse_edges: false,
+            collapse_subhypergraphs: false,
+            rankdir: RankDir::default(),
+            sorted: false,
+            url: None,
+            tooltip: None,
+            color: None,
         }
     }
 }
@@ -131,16 +408,26 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
     /// Transforms into a [dot language](https://graphviz.org/doc/info/lang.html) representation, from Graphviz.
     ///
     /// Hyperedges are represented as nodes without borders.
+    ///
+    /// # Ordering
+    ///
+    /// The output is deterministic. Each hypergraph emits its nodes, edges, links and subhypergraphs, in this order,
+    /// each section in insertion order, or sorted by id if the formatter is [`sorted`](struct.DotFormatter.html#structfield.sorted).
     pub fn as_dot<F>(&self, formatter: F) -> String
     where
         F: Into<Option<DotFormatter<N, E, H, L>>>,
     {
-        self.as_dot_impl(vec![], &formatter.into())
+        self.as_dot_impl(vec![], &formatter.into(), None)
     }
+
+    /// Dot representation of `self`, located at `pre_id`.
+    ///
+    /// `label` is the label of `self` when it was already computed by the enclosing hypergraph.
     fn as_dot_impl(
         &self,
         pre_id: Vec<usize>,
         formatter_option: &Option<DotFormatter<N, E, H, L>>,
+        label: Option<Label>,
     ) -> String {
         let mut dot = String::new();
         if self.class().is_main() {
@@ -149,93 +436,260 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
             dot += &format!("subgraph \"cluster_{:?}\" ", pre_id) // shows as cluster, if supported
         }
         dot.push_str("{\n\tcompound = true;\n");
+        // Layout direction, only supported in the main graph
+        if self.class().is_main() {
+            if let Some(formatter) = formatter_option {
+                if formatter.rankdir != RankDir::default() {
+                    dot += &format!("\trankdir={};\n", formatter.rankdir);
+                }
+            }
+        }
         // Hypergraph value
         match formatter_option {
             Some(formatter) => {
-                dot += &format!(
-                    "\tlabel = \"{}\";\n",
-                    (formatter.hypergraph)(&pre_id, self.value())
-                );
+                let label = label.unwrap_or_else(|| (formatter.hypergraph)(&pre_id, self.value()));
+                dot += &format!("\tlabel = {};\n", label.to_dot());
             }
             None => {
                 dot += &format!("\tlabel = \"{:?}\";\n", pre_id);
             }
         }
+        let interactive_atributes = |id: &Vec<usize>, value| match formatter_option {
+            None => String::new(),
+            Some(formatter) => formatter
+                .interactive_atributes(id, value)
+                .into_iter()
+                .map(|(name, value)| format!(", {}=\"{}\"", name, value))
+                .collect::<String>(),
+        };
+        if let Some(formatter) = formatter_option {
+            let value = ElementValue::Hypergraph {
+                value: self.value().as_ref(),
+            };
+            for (name, value) in formatter.interactive_atributes(&pre_id, value) {
+                dot += &format!("\t{} = \"{}\";\n", name, value);
+            }
+        }
         // Invisible node to refer to the hypergraph in edges
         dot += &format!(
             "\t\"{:?}\" [label = \"\", height = 0, width = 0, style = invisible];\n",
             pre_id
         );
 
+        let sorted = formatter_option
+            .as_ref()
+            .is_some_and(|formatter| formatter.sorted);
+
         // Nodes
         let raw_nodes = self.raw_nodes();
-        for post_id in raw_nodes.keys() {
+        for post_id in &local_ids(raw_nodes.keys(), sorted) {
             let mut id = pre_id.clone();
             id.push(*post_id);
             let label = match formatter_option {
-                None => format!("{:?}", id),
-                Some(formatter) => (formatter.node)(&id, &raw_nodes[post_id].0),
+                None => format!("\"{:?}\"", id),
+                Some(formatter) => (formatter.node)(&id, &raw_nodes[post_id].0).to_dot(),
+            };
+            let value = ElementValue::Node {
+                value: &raw_nodes[post_id].0,
             };
-            dot += &format!("\t\"{:?}\" [label=\"{}\"];\n", &id, label);
+            dot += &format!(
+                "\t\"{:?}\" [label={}{}];\n",
+                &id,
+                label,
+                interactive_atributes(&id, value)
+            );
         }
 
         // Edges
+        let collapse_edges = formatter_option
+            .as_ref()
+            .is_some_and(|formatter| formatter.collapse_edges);
+        let mut collapsed_links = Vec::new();
+        let collapse_subhypergraphs = formatter_option
+            .as_ref()
+            .is_some_and(|formatter| formatter.collapse_subhypergraphs);
+        // Arrows from (or to) elements of this level, or of its collapsed subhypergraphs
+        let arrow = |source: &Vec<usize>, target: &Vec<usize>, mut atributes: String| {
+            if collapse_subhypergraphs {
+                let level = pre_id.len() + 1;
+                format!(
+                    "\t\"{:?}\" -> \"{:?}\" [{}];\n",
+                    &source[0..level],
+                    &target[0..level],
+                    atributes
+                )
+            } else {
+                atributes += &self.cluster_atributes(&pre_id, source, target);
+                format!("\t\"{:?}\" -> \"{:?}\" [{}];\n", source, target, atributes)
+            }
+        };
         let raw_edges = self.raw_edges();
-        for post_id in raw_edges.keys() {
+        for post_id in &local_ids(raw_edges.keys(), sorted) {
             let mut id = pre_id.clone();
             id.push(*post_id);
             let label = match formatter_option {
-                None => format!("{:?}", id),
-                Some(formatter) => (formatter.edge)(&id, &raw_edges[post_id].0),
+                None => format!("\"{:?}\"", id),
+                Some(formatter) => (formatter.edge)(&id, &raw_edges[post_id].0).to_dot(),
+            };
+            let value = ElementValue::Edge {
+                value: &raw_edges[post_id].0,
             };
-            dot += &format!("\t\"{:?}\" [style = dotted, label=\"{}\"];\n", &id, label);
+            if collapse_edges {
+                if let Some((source, target, links)) = self.collapsible_edge(&pre_id, *post_id) {
+                    let mut atributes = String::new();
+                    atributes += &format!("label = {}", label);
+                    atributes += &interactive_atributes(&id, value);
+                    dot += &arrow(source, target, atributes);
+                    collapsed_links.extend(links);
+                    continue;
+                }
+            }
+            dot += &format!(
+                "\t\"{:?}\" [style = dotted, label={}{}];\n",
+                &id,
+                label,
+                interactive_atributes(&id, value)
+            );
         }
 
         // Links
         let raw_links = self.raw_links();
-        for post_id in raw_links.keys() {
+        for post_id in &local_ids(raw_links.keys(), sorted) {
+            if collapsed_links.contains(post_id) {
+                continue;
+            }
             let mut id = pre_id.clone();
             id.push(*post_id);
             let link_full = &raw_links[post_id];
             let label = match formatter_option {
-                None => format!("{:?}", id),
-                Some(formatter) => (formatter.link)(&id, &link_full.0),
+                None => format!("\"{:?}\"", id),
+                Some(formatter) => (formatter.link)(&id, &link_full.0).to_dot(),
             };
             let mut atributes = String::new();
-            atributes += &format!("label = \"{}\"", label);
-            // Recall: Links in a hypergraph can only refer to elements inside that hypergraph.
-            let local_source: Vec<_> = link_full.1.clone().into_iter().skip(pre_id.len()).collect();
-            if self.contains_subhypergraph(&local_source) {
-                atributes += &format!(", ltail = \"cluster_{:?}\"", link_full.1);
-            }
-            let local_target: Vec<_> = link_full.2.clone().into_iter().skip(pre_id.len()).collect();
-            if self.contains_subhypergraph(&local_target) {
-                atributes += &format!(", lhead = \"cluster_{:?}\"", link_full.2);
-            }
-            dot += &format!(
-                "\t\"{:?}\" -> \"{:?}\" [{}];\n",
-                &link_full.1, &link_full.2, atributes
-            );
+            atributes += &format!("label = {}", label);
+            let value = ElementValue::Link {
+                value: link_full.0.as_ref(),
+            };
+            atributes += &interactive_atributes(&id, value);
+            dot += &arrow(&link_full.1, &link_full.2, atributes);
         }
 
         // Subhypergraphs
         let raw_hypergraphs = self.raw_hypergraphs();
-        for post_id in raw_hypergraphs.keys() {
+        for post_id in &local_ids(raw_hypergraphs.keys(), sorted) {
             let mut id = pre_id.clone();
             id.push(*post_id);
             let hypergraph_full = &raw_hypergraphs[post_id];
-            dot += &hypergraph_full.0.as_dot_impl(id, formatter_option);
+            let label = formatter_option
+                .as_ref()
+                .and_then(|formatter| formatter.hypergraph_full.as_ref())
+                .map(|hypergraph_formatter| hypergraph_formatter(&id, &hypergraph_full.0));
+            if collapse_subhypergraphs {
+                // Never fails since collapse_subhypergraphs is only set by a formatter
+                let formatter = formatter_option.as_ref().unwrap();
+                let hypergraph_value = hypergraph_full.0.value();
+                let label = label
+                    .unwrap_or_else(|| (formatter.hypergraph)(&id, hypergraph_value))
+                    .to_dot();
+                let value = ElementValue::Hypergraph {
+                    value: hypergraph_value.as_ref(),
+                };
+                dot += &format!(
+                    "\t\"{:?}\" [shape = box, label={}{}];\n",
+                    &id,
+                    label,
+                    interactive_atributes(&id, value)
+                );
+            } else {
+                dot += &hypergraph_full.0.as_dot_impl(id, formatter_option, label);
+            }
         }
 
         dot.push_str("}\n");
         dot
     }
 
-    /// Saves the output of [`as_dot`] and draws and saves the graph as a svg file.
+    /// Returns the `ltail` and `lhead` atributes of an arrow from `source` to `target`,
+    /// drawn in the hypergraph `pre_id`, so that arrows to hypergraphs point to their cluster.
+    fn cluster_atributes(&self, pre_id: &[usize], source: &[usize], target: &[usize]) -> String {
+        let mut atributes = String::new();
+        // Recall: Links in a hypergraph can only refer to elements inside that hypergraph.
+        if self.contains_subhypergraph(&source[pre_id.len()..]) {
+            atributes += &format!(", ltail = \"cluster_{:?}\"", source);
+        }
+        if self.contains_subhypergraph(&target[pre_id.len()..]) {
+            atributes += &format!(", lhead = \"cluster_{:?}\"", target);
+        }
+        atributes
+    }
+
+    /// Returns the source, target and local ids of links of the edge `post_id`, if the edge can be drawn as a single arrow.
     ///
-    /// The files are named through `file_name`.
+    /// This is the case when the edge has exactly two links, one incoming and one outgoing,
+    /// both without value and living in the same hypergraph as the edge.
+    fn collapsible_edge(
+        &self,
+        pre_id: &[usize],
+        post_id: usize,
+    ) -> Option<(&Vec<usize>, &Vec<usize>, [usize; 2])> {
+        let links = &self.raw_edges().get(&post_id)?.1;
+        if links.len() != 2 {
+            return None;
+        }
+        let local_link = |direction: Direction| {
+            let (link_id, _) = links.iter().find(|(_, d)| d == &direction)?;
+            if link_id.len() == pre_id.len() + 1 && link_id.starts_with(pre_id) {
+                link_id.last().copied()
+            } else {
+                None
+            }
+        };
+        let incoming = local_link(Direction::Incoming)?;
+        let outgoing = local_link(Direction::Outgoing)?;
+        let (incoming_value, source, _) = self.raw_links().get(&incoming)?;
+        let (outgoing_value, _, target) = self.raw_links().get(&outgoing)?;
+        if incoming_value.is_some() || outgoing_value.is_some() {
+            return None;
+        }
+        Some((source, target, [incoming, outgoing]))
+    }
+}
+
+/// # Files
+///
+/// Save and open visualizations, calling external programs. Requires the `draw` feature (enabled by default).
+#[cfg(feature = "draw")]
+impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
+    /// Saves the output of [`as_dot`](Self::as_dot) in the file `file_name.dot`, inside the subdirectory `dot` of `dir`.
     ///
-    /// This is just a shorthand for running the command [`dot`] of Graphviz in the result of the [`as_dot`] method
+    /// Returns the path of the saved file.
+    pub fn write_dot_in<F>(
+        &self,
+        formatter: F,
+        file_name: impl Display,
+        dir: impl AsRef<Path>,
+    ) -> io::Result<PathBuf>
+    where
+        F: Into<Option<DotFormatter<N, E, H, L>>>,
+    {
+        let dot_path = output_path(dir, "dot", file_name)?;
+        let mut dot_file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&dot_path)?;
+        write!(dot_file, "{}", self.as_dot(formatter))?;
+        Ok(dot_path)
+    }
+
+    /// Saves the output of [`as_dot`](Self::as_dot) and draws and saves the graph as a svg file.
+    ///
+    /// Returns the `dot` process and the path of the svg file, which is ready once the process finishes.
+    ///
+    /// The files are named through `file_name` and saved in [`DEFAULT_OUTPUT_DIR`].
+    /// See [`draw_in`](Self::draw_in) to choose the directory.
+    ///
+    /// This is just a shorthand for running the command [`dot`] of Graphviz in the result of the [`as_dot`](Self::as_dot) method
     /// and saving all files.
     ///
     /// # Requirements
@@ -248,37 +702,48 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
     ///
     /// [`dot`]: https://graphviz.org/doc/info/command.html
     /// [`Graphviz`]: https://graphviz.org/
-    pub fn draw<F>(&self, formatter: F, file_name: impl Display) -> io::Result<process::Child>
+    pub fn draw<F>(
+        &self,
+        formatter: F,
+        file_name: impl Display,
+    ) -> io::Result<(process::Child, PathBuf)>
     where
         F: Into<Option<DotFormatter<N, E, H, L>>>,
     {
-        fs::create_dir_all("target/ferret_hypergraph/dot/")?;
-        let dot_path = format!("target/ferret_hypergraph/dot/{}.dot", file_name);
-        let mut dot_file = fs::OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(&dot_path)?;
-        write!(dot_file, "{}", self.as_dot(formatter))?;
+        self.draw_in(formatter, file_name, DEFAULT_OUTPUT_DIR)
+    }
+
+    /// Same as [`draw`](Self::draw), but the files are saved in the subdirectories `dot` and `svg` of `dir`.
+    pub fn draw_in<F>(
+        &self,
+        formatter: F,
+        file_name: impl Display,
+        dir: impl AsRef<Path>,
+    ) -> io::Result<(process::Child, PathBuf)>
+    where
+        F: Into<Option<DotFormatter<N, E, H, L>>>,
+    {
+        let dir = dir.as_ref();
+        let dot_path = self.write_dot_in(formatter, &file_name, dir)?;
 
-        fs::create_dir_all("target/ferret_hypergraph/svg/")?;
+        let svg_path = output_path(dir, "svg", file_name)?;
         let child = process::Command::new("dot")
             .arg("-Tsvg")
             .arg(&dot_path)
-            .args(&[
-                "-o",
-                &format!("target/ferret_hypergraph/svg/{}.svg", file_name),
-            ])
+            .arg("-o")
+            .arg(&svg_path)
             .spawn()
             .expect("failed running graphviz dot. Is graphviz installed?");
 
-        Ok(child)
+        Ok((child, svg_path))
     }
 
-    /// On top of applying the [`draw`] method, it (asynchroniously) renders the svg file into a png file
+    /// On top of applying the [`draw`](Self::draw) method, it (asynchroniously) renders the svg file into a png file
     /// and opens it (using [`emulsion`]) for quick inspection.
     ///
-    /// This is just a shorthand for running the method [`draw`], then commands [`resvg`] and [`emulsion`].
+    /// Returns the `emulsion` process and the path of the png file.
+    ///
+    /// This is just a shorthand for running the method [`draw`](Self::draw), then commands [`resvg`] and [`emulsion`].
     ///
     /// # Requirements
     ///
@@ -295,30 +760,42 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
         &self,
         formatter: F,
         file_name: impl Display,
-    ) -> io::Result<process::Child>
+    ) -> io::Result<(process::Child, PathBuf)>
     where
         F: Into<Option<DotFormatter<N, E, H, L>>>,
     {
-        self.draw(formatter, &file_name)?
-            .wait()
-            .expect("dot failed to run.");
-        fs::create_dir_all("target/ferret_hypergraph/svg/")?;
-        fs::create_dir_all("target/ferret_hypergraph/png/")?;
+        self.draw_and_show_in(formatter, file_name, DEFAULT_OUTPUT_DIR)
+    }
+
+    /// Same as [`draw_and_show`](Self::draw_and_show), but the files are saved in the subdirectories `dot`, `svg` and `png` of `dir`.
+    pub fn draw_and_show_in<F>(
+        &self,
+        formatter: F,
+        file_name: impl Display,
+        dir: impl AsRef<Path>,
+    ) -> io::Result<(process::Child, PathBuf)>
+    where
+        F: Into<Option<DotFormatter<N, E, H, L>>>,
+    {
+        let dir = dir.as_ref();
+        let (mut child, svg_path) = self.draw_in(formatter, &file_name, dir)?;
+        child.wait().expect("dot failed to run.");
+        let png_path = output_path(dir, "png", file_name)?;
 
         process::Command::new("resvg")
-            .arg(&format!("target/ferret_hypergraph/svg/{}.svg", file_name))
-            .arg(&format!("target/ferret_hypergraph/png/{}.png", file_name))
+            .arg(&svg_path)
+            .arg(&png_path)
             .spawn()
             .expect("failed running resvg to transform svg to png format. Is resvg installed?")
             .wait()
             .expect("resvg failed to run.");
 
         let child = process::Command::new("emulsion")
-            .arg(&format!("target/ferret_hypergraph/png/{}.png", file_name))
+            .arg(&png_path)
             .spawn()
             .expect("failed running emulsion to open png. Is emulsion installed?");
 
-        Ok(child)
+        Ok((child, png_path))
     }
 
     /// Renders the hypergraph as a png (using [`dot`])
@@ -326,6 +803,8 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
     ///
     /// This is the fastest way to visualize a hypergraph.
     ///
+    /// Returns the `emulsion` process and the path of the png file.
+    ///
     /// This is just a shorthand for running the commands [`dot`] to generate a png file and open it with [`emulsion`].
     ///
     /// # Requirements
@@ -339,19 +818,34 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
     ///
     /// [`dot`]: https://graphviz.org/doc/info/command.html
     /// [`emulsion`]: https://github.com/ArturKovacs/emulsion
-    pub fn show<F>(&self, formatter: F, file_name: impl Display) -> io::Result<process::Child>
+    pub fn show<F>(
+        &self,
+        formatter: F,
+        file_name: impl Display,
+    ) -> io::Result<(process::Child, PathBuf)>
     where
         F: Into<Option<DotFormatter<N, E, H, L>>>,
     {
-        fs::create_dir_all("target/ferret_hypergraph/png/")?;
+        self.show_in(formatter, file_name, DEFAULT_OUTPUT_DIR)
+    }
+
+    /// Same as [`show`](Self::show), but the png file is saved in the subdirectory `png` of `dir`.
+    pub fn show_in<F>(
+        &self,
+        formatter: F,
+        file_name: impl Display,
+        dir: impl AsRef<Path>,
+    ) -> io::Result<(process::Child, PathBuf)>
+    where
+        F: Into<Option<DotFormatter<N, E, H, L>>>,
+    {
+        let png_path = output_path(dir, "png", file_name)?;
 
         let mut child = process::Command::new("dot")
             .arg("-Tpng")
             .stdin(process::Stdio::piped())
-            .args(&[
-                "-o",
-                &format!("target/ferret_hypergraph/png/{}.png", file_name),
-            ])
+            .arg("-o")
+            .arg(&png_path)
             .spawn()
             .expect("failed running graphviz dot. Is graphviz installed?");
 
@@ -359,40 +853,423 @@ impl<N, E, H, L, Ty: HypergraphClass> Hypergraph<N, E, H, L, Ty> {
 	        .stdin
 	        .as_mut()
 	        .unwrap()
-	        .write(self.as_dot(formatter).as_bytes())
+	        .write_all(self.as_dot(formatter).as_bytes())
 	        .expect("Writing failed in child process. We could not pass the dot representation of the hypergraph to dot.");
         child.wait()
             .expect("failed running graphviz dot. If graphviz is running well in your computer, contact us!");
 
         let child = process::Command::new("emulsion")
-            .arg(&format!("target/ferret_hypergraph/png/{}.png", file_name))
+            .arg(&png_path)
             .spawn()
             .expect("failed running emulsion to open png. Is emulsion installed?");
 
-        Ok(child)
+        Ok((child, png_path))
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<N, E, H, L, Ty> Hypergraph<N, E, H, L, Ty>
+where
+    N: serde::Serialize,
+    E: serde::Serialize,
+    H: serde::Serialize,
+    L: serde::Serialize,
+{
+    /// Transforms into the JSON format of [Cytoscape.js](https://js.cytoscape.org/#notation/elements-json).
+    ///
+    /// Nodes, edges and subhypergraphs are all represented as Cytoscape nodes,
+    /// while links are represented as Cytoscape edges.
+    /// Every element has its id (formatted as in `as_dot`), its kind (`"node"`, `"edge"`, `"hypergraph"` or `"link"`)
+    /// and its value in `data`. Elements inside a subhypergraph refer to it as their `parent`,
+    /// so that subhypergraphs are shown as compound nodes. Requires the `serde_json` feature.
+    ///
+    /// # Panics
+    ///
+    /// If the serialization of any value fails.
+    pub fn to_cytoscape_json(&self) -> serde_json::Value {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        for id in self.ids().skip(1) {
+            let mut data = serde_json::Map::new();
+            data.insert("id".to_string(), format!("{:?}", id).into());
+            if id.len() > 1 {
+                let parent = &id[0..id.len() - 1];
+                data.insert("parent".to_string(), format!("{:?}", parent).into());
+            }
+            let (kind, value) = match self.element_value(&id).unwrap() {
+                // Never fails since id refers to an element
+                ElementValue::Edge { value } => ("edge", serde_json::to_value(value)),
+                ElementValue::Hypergraph { value } => ("hypergraph", serde_json::to_value(value)),
+                ElementValue::Link { value } => ("link", serde_json::to_value(value)),
+                ElementValue::Node { value } => ("node", serde_json::to_value(value)),
+            };
+            data.insert("kind".to_string(), kind.into());
+            data.insert(
+                "value".to_string(),
+                value.expect("failed to serialize the value of an element"),
+            );
+            if kind == "link" {
+                let (source, target) = self.link_endpoints(&id).unwrap(); // Never fails since id refers to a link
+                data.insert("source".to_string(), format!("{:?}", source).into());
+                data.insert("target".to_string(), format!("{:?}", target).into());
+                edges.push(serde_json::json!({ "data": data }));
+            } else {
+                nodes.push(serde_json::json!({ "data": data }));
+            }
+        }
+        serde_json::json!({ "elements": { "nodes": nodes, "edges": edges } })
     }
 }
 
-impl<'a, N, E, H, L, Ty> Into<tabbycat::Graph<'a>> for &'a Hypergraph<N, E, H, L, Ty>
+impl<'a, N, E, H, L, Ty> From<&'a Hypergraph<N, E, H, L, Ty>> for tabbycat::Graph<'a>
 where
     H: Display,
 {
-    fn into(self) -> tabbycat::Graph<'a> {
+    fn from(_hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> Self {
         todo!()
     }
 }
 
+/// Returns the local ids of a section, in the order they are emitted.
+fn local_ids<'a>(keys: impl Iterator<Item = &'a usize>, sorted: bool) -> Vec<usize> {
+    let mut local_ids: Vec<usize> = keys.copied().collect();
+    if sorted {
+        local_ids.sort_unstable();
+    }
+    local_ids
+}
+
+/// Returns the path `dir/extension/file_name.extension`, creating its parent directory if necessary.
+#[cfg(feature = "draw")]
+fn output_path(
+    dir: impl AsRef<Path>,
+    extension: &str,
+    file_name: impl Display,
+) -> io::Result<PathBuf> {
+    let parent = dir.as_ref().join(extension);
+    fs::create_dir_all(&parent)?;
+    Ok(parent.join(format!("{}.{}", file_name, extension)))
+}
+
+/// Escapes `text` to be used inside an HTML-like label in the dot language.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escapes `label` to be used inside a quoted string in the dot language.
+///
+/// Backslashes and double quotes are escaped, while newlines are replaced by the dot line break `\n`.
+fn escape_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for c in label.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => (),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn as_dot_collapse_edges() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_node("five");
+        h.add_edge([1], [5], "six").unwrap();
+        h.add_link([6], [5], "nine").unwrap();
+
+        let dot = h.as_dot(DotFormatter::display());
+        assert_eq!(dot.matches("->").count(), 5);
+
+        let mut formatter = DotFormatter::display();
+        formatter.set_collapse_edges(true);
+        let dot = h.as_dot(formatter);
+        assert_eq!(dot.matches("->").count(), 4);
+        assert!(dot.contains("\t\"[0]\" -> \"[1]\" [label = \"two\"];\n"));
+        assert!(!dot.contains("\t\"[2]\" ["));
+        assert!(dot.contains("\t\"[6]\" [style = dotted, label=\"six\"];\n"));
+    }
+
+    #[test]
+    fn as_dot_sorted() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("zero", [5]).unwrap();
+        h.add_node_in("one", [5]).unwrap();
+        h.add_node("six");
+        h.add_edge([6], [0], "seven").unwrap();
+
+        // Same elements, with every map in reverse insertion order
+        let mut reversed = h.clone();
+        reversed.nodes.reverse();
+        reversed.edges.reverse();
+        reversed.links.reverse();
+        reversed.subhypergraph_mut([5]).unwrap().nodes.reverse();
+        assert_eq!(reversed.validate(), Ok(()));
+        assert_ne!(
+            h.as_dot(DotFormatter::display()),
+            reversed.as_dot(DotFormatter::display())
+        );
+
+        let sorted = || {
+            let mut formatter = DotFormatter::display();
+            formatter.set_sorted(true);
+            formatter
+        };
+        assert_eq!(h.as_dot(sorted()), reversed.as_dot(sorted()));
+        assert_eq!(h.as_dot(sorted()), h.as_dot(DotFormatter::display()));
+    }
+
+    #[test]
+    fn as_dot_escape() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::with_value("a\\b");
+        h.add_node("say \"hi\"");
+        h.add_node("two\nlines");
+        h.add_edge([0], [1], "\"").unwrap();
+        h.add_link([2], [1], "\\").unwrap();
+
+        let dot = h.as_dot(DotFormatter::display());
+        assert!(dot.contains("\tlabel = \"a\\\\b\";\n"));
+        assert!(dot.contains("\t\"[0]\" [label=\"say \\\"hi\\\"\"];\n"));
+        assert!(dot.contains("\t\"[1]\" [label=\"two\\nlines\"];\n"));
+        assert!(dot.contains("\t\"[2]\" [style = dotted, label=\"\\\"\"];\n"));
+        assert!(dot.contains("\t\"[2]\" -> \"[1]\" [label = \"\\\\\"];\n"));
+        // Raw labels never reach the output
+        assert!(!dot.contains("label = \"a\\b\""));
+        assert!(!dot.contains("say \"hi\""));
+        assert!(!dot.contains("two\nlines"));
+        assert!(!dot.contains("label=\"\"\""));
+        assert!(!dot.contains("label = \"\\\"]"));
+    }
+
+    #[test]
+    fn as_dot_html() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("a<b");
+        h.add_node("one");
+
+        let mut formatter = DotFormatter::display();
+        formatter.set_node(|_, node: &&str| {
+            if node.contains('<') {
+                Label::Html("<B>bold</B>".to_string())
+            } else {
+                Label::Text(node.to_string())
+            }
+        });
+        let dot = h.as_dot(formatter);
+        assert!(dot.contains("\t\"[0]\" [label=<<B>bold</B>>];\n"));
+        assert!(dot.contains("\t\"[1]\" [label=\"one\"];\n"));
+
+        let dot = h.as_dot(DotFormatter::html());
+        assert!(dot.contains(
+            "\t\"[0]\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD>[0]</TD></TR><TR><TD>a&lt;b</TD></TR></TABLE>>];\n"
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "draw")]
+    fn write_dot_in() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        let dir = std::env::temp_dir().join(format!("ferret_hypergraph_{}", process::id()));
+
+        let dot_path = h.write_dot_in(None, "write_dot_in", &dir).unwrap();
+        assert_eq!(dot_path, dir.join("dot").join("write_dot_in.dot"));
+        assert_eq!(fs::read_to_string(&dot_path).unwrap(), h.as_dot(None));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "draw")]
+    fn draw_in() {
+        // Requires graphviz
+        if process::Command::new("dot").arg("-V").output().is_err() {
+            return;
+        }
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        let dir = std::env::temp_dir().join(format!("ferret_hypergraph_draw_{}", process::id()));
+
+        let (mut child, svg_path) = h.draw_in(None, "draw_in", &dir).unwrap();
+        child.wait().unwrap();
+        assert_eq!(svg_path, dir.join("svg").join("draw_in.svg"));
+        assert!(svg_path.is_file());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "draw")]
+    fn output_path() {
+        let dir = std::env::temp_dir().join(format!("ferret_hypergraph_path_{}", process::id()));
+
+        let path = super::output_path(&dir, "svg", "name").unwrap();
+        assert_eq!(path, dir.join("svg").join("name.svg"));
+        assert!(path.parent().unwrap().is_dir());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn as_dot_url_tooltip() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+
+        let mut formatter = DotFormatter::display();
+        formatter
+            .set_url(|_, value| match value {
+                ElementValue::Node { value } => Some(format!("https://example.com/{}", value)),
+                _ => None,
+            })
+            .set_tooltip(|id, value| match value {
+                ElementValue::Link { .. } | ElementValue::Hypergraph { .. } => {
+                    Some(format!("{:?}", id))
+                }
+                _ => None,
+            });
+        let dot = h.as_dot(formatter);
+        assert!(dot.contains("\t\"[0]\" [label=\"zero\", URL=\"https://example.com/zero\"];\n"));
+        assert!(dot.contains("\t\"[2]\" [style = dotted, label=\"two\"];\n"));
+        assert!(dot.contains("\t\"[0]\" -> \"[2]\" [label = \"\", tooltip=\"[3]\"];\n"));
+        assert!(dot.contains("\ttooltip = \"[5]\";\n"));
+        assert_eq!(dot.matches("URL").count(), 2);
+    }
+
+    #[test]
+    fn as_dot_summary() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_hypergraph("sub");
+        h.add_node_in("two", [1]).unwrap();
+        h.add_node_in("three", [1]).unwrap();
+        h.add_hypergraph_in("four", [1]).unwrap();
+        h.add_node_in("five", [1, 2]).unwrap();
+
+        let dot = h.as_dot(DotFormatter::summary());
+        assert!(dot.contains("\t\"[1]\" [shape = box, label=\"sub (3 nodes)\"];\n"));
+        assert!(!dot.contains("two"));
+
+        let mut formatter = DotFormatter::display();
+        formatter.set_hypergraph_full(|_, hypergraph| format!("{} nodes", hypergraph.node_count()));
+        let dot = h.as_dot(formatter);
+        assert!(dot.contains("\tlabel = \"2 nodes\";\n"));
+        assert!(dot.contains("\tlabel = \"1 nodes\";\n"));
+    }
+
+    #[test]
+    fn as_dot_color_components() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_node("two");
+        h.add_node("three");
+        h.add_edge([0], [1], "four").unwrap();
+        h.add_edge([2], [3], "seven").unwrap();
+
+        let mut formatter = DotFormatter::display();
+        formatter.color_components(h.component_labeling());
+        let dot = h.as_dot(formatter);
+        assert!(dot.contains("\t\"[0]\" [label=\"zero\", color=\"red\"];\n"));
+        assert!(dot.contains("\t\"[1]\" [label=\"one\", color=\"red\"];\n"));
+        assert!(dot.contains("\t\"[2]\" [label=\"two\", color=\"blue\"];\n"));
+        assert!(dot.contains("\t\"[3]\" [label=\"three\", color=\"blue\"];\n"));
+        assert_eq!(dot.matches("color").count(), 4);
+    }
+
+    #[test]
+    fn as_dot_collapse_subhypergraphs() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_node_in("seven", [5]).unwrap();
+        h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
+        h.add_link([2], [5, 0], "eleven").unwrap();
+        h.add_hypergraph_in("twelve", [5]).unwrap();
+
+        let mut formatter = DotFormatter::display();
+        formatter.set_collapse_subhypergraphs(true);
+        let dot = h.as_dot(formatter);
+        assert!(!dot.contains("subgraph \"cluster_"));
+        assert!(!dot.contains("six"));
+        assert!(dot.contains("\t\"[5]\" [shape = box, label=\"five\"];\n"));
+        assert!(dot.contains("\t\"[2]\" -> \"[5]\" [label = \"eleven\"];\n"));
+        assert_eq!(dot.matches("->").count(), 3);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn to_cytoscape_json() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_hypergraph("five");
+        h.add_node_in("six", [5]).unwrap();
+        h.add_link([2], [5, 0], "seven").unwrap();
+
+        let json = h.to_cytoscape_json();
+        let nodes = json["elements"]["nodes"].as_array().unwrap();
+        let edges = json["elements"]["edges"].as_array().unwrap();
+        assert_eq!(nodes.len(), 5);
+        assert_eq!(edges.len(), 3);
+        assert_eq!(
+            nodes[4],
+            serde_json::json!({ "data": { "id": "[5, 0]", "parent": "[5]", "kind": "node", "value": "six" } })
+        );
+        assert_eq!(
+            edges[2],
+            serde_json::json!({ "data": { "id": "[6]", "kind": "link", "value": "seven", "source": "[2]", "target": "[5, 0]" } })
+        );
+    }
+
+    #[test]
+    fn as_dot_rankdir() {
+        let mut h = Hypergraph::<&str, &str, &str, &str>::new();
+        h.add_node("zero");
+        h.add_hypergraph("one");
+
+        let dot = h.as_dot(DotFormatter::display());
+        assert!(!dot.contains("rankdir"));
+
+        let mut formatter = DotFormatter::display();
+        formatter.set_rankdir(RankDir::LR);
+        let dot = h.as_dot(formatter);
+        assert_eq!(dot.matches("rankdir=LR;").count(), 1);
+    }
+
     #[test]
     fn as_dot() {
         let mut h = Hypergraph::<&str, &str, &str, &str>::new();
         h.add_node("zero");
         h.add_node("one");
         h.add_edge([0], [1], "two").unwrap();
-        h.add_hypergraph("five").unwrap();
+        h.add_hypergraph("five");
         h.add_node_in("six", [5]).unwrap();
         h.add_node_in("seven", [5]).unwrap();
         h.add_edge_in([5, 0], [5, 1], "eight", [5]).unwrap();
@@ -1,4 +1,4 @@
-use crate::{traits, Hypergraph};
+use crate::{traits, walkers::WalkIds, Hypergraph};
 
 #[derive(Debug)]
 pub struct WalkIter<'a, N, E, H, L, Ty, Walker> {
@@ -24,4 +24,14 @@ where
     fn next(&mut self) -> std::option::Option<Walker::Item> {
         self.walker.walk_next(self.hypergraph)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.walker.walk_size_hint(self.hypergraph)
+    }
+}
+
+impl<'a, N, E, H, L, Ty> DoubleEndedIterator for WalkIter<'a, N, E, H, L, Ty, WalkIds> {
+    fn next_back(&mut self) -> Option<Vec<usize>> {
+        self.walker.walk_next_back(self.hypergraph)
+    }
 }
@@ -3,7 +3,7 @@
 //! This library provides the [`Hypergraph`] struct.
 //!
 #![cfg_attr(
-	all(),
+	feature = "draw",
 	doc = ::embed_doc_image::embed_image!("hypergraph_concept", "images/hypergraph_concept.png")
 )]
 //!
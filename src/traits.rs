@@ -24,6 +24,16 @@ pub trait Walker<'a, N, E, H, L, Ty>: Sized {
     /// Advance to the next item.
     fn walk_next(&mut self, hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> Option<Self::Item>;
 
+    /// Returns the bounds on the remaining length of the walk for `hypergraph`.
+    ///
+    /// Follows the same conventions as [`Iterator::size_hint`]. The default implementation returns `(0, None)`.
+    fn walk_size_hint(
+        &self,
+        _hypergraph: &'a Hypergraph<N, E, H, L, Ty>,
+    ) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
     /// Create an iterator fixing a reference to a hypergraph.
     fn build_iter(
         self,
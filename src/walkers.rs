@@ -1,5 +1,11 @@
 mod walk_ids;
+mod walk_ids_breadth_first;
 mod walk_neighbors;
+mod walk_neighbors_snapshot;
+mod walk_neighbors_with_link;
 
 pub use walk_ids::WalkIds;
+pub use walk_ids_breadth_first::WalkIdsBreadthFirst;
 pub use walk_neighbors::WalkNeighbors;
+pub use walk_neighbors_snapshot::WalkNeighborsSnapshot;
+pub use walk_neighbors_with_link::WalkNeighborsWithLink;
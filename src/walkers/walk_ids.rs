@@ -6,7 +6,16 @@ use crate::{traits::Walker, Hypergraph};
 #[derive(Debug, Clone)]
 pub struct WalkIds {
     next_id: Option<Vec<usize>>,
+    /// Last id returned when walking backwards, `None` if the walk has not started from the back.
+    back_id: Option<Vec<usize>>,
 }
+
+impl Default for WalkIds {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WalkIds {
     pub fn new() -> Self {
         Self::new_from(vec![])
@@ -15,7 +24,29 @@ impl WalkIds {
     pub fn new_from(next_id: impl Into<Option<Vec<usize>>>) -> Self {
         WalkIds {
             next_id: next_id.into(),
+            back_id: None,
+        }
+    }
+
+    /// Step to the previous id in the walk for `hypergraph`, starting from the last valid id.
+    ///
+    /// Walking from both ends never returns the same id twice.
+    pub fn walk_next_back<N, E, H, L, Ty>(
+        &mut self,
+        hypergraph: &Hypergraph<N, E, H, L, Ty>,
+    ) -> Option<Vec<usize>> {
+        let front_id = self.next_id.as_ref()?;
+        let id = match &self.back_id {
+            None => hypergraph.prev_id([hypergraph.next_local_id()]),
+            Some(back_id) => hypergraph.prev_id(back_id),
+        }?;
+        if &id < front_id {
+            // Both ends met
+            self.next_id = None;
+            return None;
         }
+        self.back_id = Some(id.clone());
+        Some(id)
     }
 }
 
@@ -26,6 +57,13 @@ impl<'a, N, E, H, L, Ty> Walker<'a, N, E, H, L, Ty> for WalkIds {
         match &self.next_id {
             None => None,
             Some(id) => {
+                if let Some(back_id) = &self.back_id {
+                    if id >= back_id {
+                        // Both ends met
+                        self.next_id = None;
+                        return None;
+                    }
+                }
                 if hypergraph.contains(id) {
                     let mut next = hypergraph.next_id(id);
                     core::mem::swap(&mut next, &mut self.next_id);
@@ -38,6 +76,22 @@ impl<'a, N, E, H, L, Ty> Walker<'a, N, E, H, L, Ty> for WalkIds {
             }
         }
     }
+
+    /// The upper bound is the total number of elements in `hypergraph` (including itself).
+    /// When the walk starts at the root and has not been walked backwards, the bound is exact.
+    fn walk_size_hint(&self, hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> (usize, Option<usize>) {
+        match &self.next_id {
+            None => (0, Some(0)),
+            Some(id) => {
+                let total = hypergraph.element_count();
+                if id.is_empty() && self.back_id.is_none() {
+                    (total, Some(total))
+                } else {
+                    (0, Some(total))
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -51,14 +105,32 @@ mod tests {
         h.add_node("one");
         h.add_edge([0], [1], "two").unwrap();
         h.add_link([0], [2], "three").unwrap();
-        h.add_hypergraph("six").unwrap();
+        h.add_hypergraph("six");
         let mut id_walk = WalkIds::new();
 
-        assert_eq!(id_walk.walk_next(&h).unwrap(), vec![]);
+        assert_eq!(id_walk.walk_next(&h).unwrap(), Vec::<usize>::new());
 
         for i in 0..7 {
             assert_eq!(id_walk.walk_next(&h).unwrap(), vec![i]);
         }
         assert_eq!(id_walk.walk_next(&h), None);
     }
+
+    #[test]
+    fn walk_size_hint() {
+        let mut h = Hypergraph::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        h.add_link([0], [2], "three").unwrap();
+        h.add_hypergraph("six");
+        h.add_node_in("seven", [6]).unwrap();
+        let mut id_walk = WalkIds::new();
+
+        assert_eq!(id_walk.walk_size_hint(&h), (9, Some(9)));
+        id_walk.walk_next(&h);
+        assert_eq!(id_walk.walk_size_hint(&h), (0, Some(9)));
+        while id_walk.walk_next(&h).is_some() {}
+        assert_eq!(id_walk.walk_size_hint(&h), (0, Some(0)));
+    }
 }
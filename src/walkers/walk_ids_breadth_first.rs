@@ -0,0 +1,115 @@
+use std::collections::VecDeque;
+
+use crate::{traits::Walker, Hypergraph};
+
+/// A “walker” object that can be used to step through a hypergraph without borrowing it.
+///
+/// Unlike [`WalkIds`](struct.WalkIds.html), which visits ids in lexicographic order,
+/// it visits ids level by level: all elements of a hypergraph are visited
+/// before descending into any of its subhypergraphs.
+#[derive(Debug, Clone)]
+pub struct WalkIdsBreadthFirst {
+    /// Ids to be returned next
+    pending: VecDeque<Vec<usize>>,
+    /// Hypergraphs whose elements are still to be visited
+    locations: VecDeque<Vec<usize>>,
+}
+
+impl Default for WalkIdsBreadthFirst {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WalkIdsBreadthFirst {
+    pub fn new() -> Self {
+        WalkIdsBreadthFirst {
+            pending: VecDeque::from(vec![vec![]]),
+            locations: VecDeque::new(),
+        }
+    }
+}
+
+impl<'a, N, E, H, L, Ty> Walker<'a, N, E, H, L, Ty> for WalkIdsBreadthFirst {
+    type Item = Vec<usize>;
+
+    /// Step to the next id in the walk for `hypergraph`.
+    ///
+    /// Elements of each level are visited in increasing order of their local id.
+    /// Ids that are no longer valid are skipped.
+    fn walk_next(&mut self, hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> Option<Self::Item> {
+        loop {
+            if let Some(id) = self.pending.pop_front() {
+                if !hypergraph.contains(&id) {
+                    continue;
+                }
+                if hypergraph.contains_hypergraph(&id) {
+                    self.locations.push_back(id.clone());
+                }
+                return Some(id);
+            }
+            let location = self.locations.pop_front()?;
+            let level = match hypergraph.hypergraph_at(&location) {
+                Ok(level) => level,
+                Err(_) => continue,
+            };
+            let mut local_ids: Vec<usize> = level
+                .raw_edges()
+                .keys()
+                .chain(level.raw_hypergraphs().keys())
+                .chain(level.raw_links().keys())
+                .chain(level.raw_nodes().keys())
+                .copied()
+                .collect();
+            local_ids.sort_unstable();
+            self.pending.extend(local_ids.into_iter().map(|local_id| {
+                let mut id = location.clone();
+                id.push(local_id);
+                id
+            }));
+        }
+    }
+
+    /// The upper bound is the total number of elements in `hypergraph` (including itself).
+    fn walk_size_hint(&self, hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> (usize, Option<usize>) {
+        if self.pending.is_empty() && self.locations.is_empty() {
+            (0, Some(0))
+        } else {
+            (0, Some(hypergraph.element_count()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walk_next() {
+        let mut h = Hypergraph::<_, (), _, ()>::new();
+        h.add_node("zero");
+        h.add_hypergraph("one");
+        h.add_node_in("two", [1]).unwrap();
+        h.add_hypergraph_in("three", [1]).unwrap();
+        h.add_node_in("four", [1, 1]).unwrap();
+        h.add_node("five");
+        let mut walk = WalkIdsBreadthFirst::new();
+
+        let mut ids = Vec::new();
+        while let Some(id) = walk.walk_next(&h) {
+            ids.push(id);
+        }
+        assert_eq!(
+            ids,
+            vec![
+                vec![],
+                vec![0],
+                vec![1],
+                vec![2],
+                vec![1, 0],
+                vec![1, 1],
+                vec![1, 1, 0]
+            ]
+        );
+    }
+}
@@ -1,16 +1,15 @@
-use crate::{traits::Walker, Direction, Hypergraph};
+use crate::{traits::Walker, walkers::WalkNeighborsWithLink, Direction, Hypergraph};
 
 /// A “walker” object that can be used to step through a hypergraph without borrowing it.
 ///
 /// Created with [`.detach()`](struct.NeighborIter.html#method.detach).
+///
+/// Links added to the source element during the walk are also visited,
+/// see [`WalkNeighborsSnapshot`](struct.WalkNeighborsSnapshot.html) to ignore them.
 #[derive(Debug, Clone)]
 pub struct WalkNeighbors {
-    /// Direction to accept
-    direction: Direction,
-    /// Link counter over the links of the source element
-    next_link: usize,
-    /// Link id and direction
-    source_id: Vec<usize>,
+    /// Walker over links, whose neighbors are returned
+    walker: WalkNeighborsWithLink,
 }
 
 impl WalkNeighbors {
@@ -25,11 +24,23 @@ impl WalkNeighbors {
         source_id: impl AsRef<[usize]>,
     ) -> Self {
         WalkNeighbors {
-            direction,
-            next_link,
-            source_id: source_id.as_ref().to_vec(),
+            walker: WalkNeighborsWithLink::new_from(direction, next_link, source_id),
         }
     }
+
+    /// Restarts the walk from the first link of the source element.
+    pub fn reset(&mut self) -> &mut Self {
+        self.walker.reset();
+        self
+    }
+
+    /// Changes the direction of the neighbors to accept.
+    ///
+    /// The walk continues from the current link, call [`reset`](#method.reset) to start over.
+    pub fn set_direction(&mut self, direction: Direction) -> &mut Self {
+        self.walker.set_direction(direction);
+        self
+    }
 }
 
 impl<'a, N, E, H, L, Ty> Walker<'a, N, E, H, L, Ty> for WalkNeighbors {
@@ -46,32 +57,14 @@ impl<'a, N, E, H, L, Ty> Walker<'a, N, E, H, L, Ty> for WalkNeighbors {
     ///
     /// If `source_id` is not a valid id for `hypergraph`, it returns `None`.
     fn walk_next(&mut self, hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> Option<Self::Item> {
-        let links = match hypergraph.links_of(&self.source_id) {
-            Ok(links) => links,
-            Err(_) => return None,
-        };
-        match links.get(self.next_link) {
-            Some((link_id, direction)) => {
-                if direction == &self.direction {
-                    let element_linkable_id = match direction {
-                        Direction::Outgoing => {
-                            let (_, target) = hypergraph.link_endpoints(link_id).unwrap(); // Never fails since link exists
-                            target
-                        }
-                        Direction::Incoming => {
-                            let (source, _) = hypergraph.link_endpoints(link_id).unwrap(); // Never fails since link exists
-                            source
-                        }
-                    };
-                    self.next_link += 1;
-                    Some(element_linkable_id)
-                } else {
-                    self.next_link += 1;
-                    return self.walk_next(hypergraph);
-                }
-            }
-            None => None,
-        }
+        self.walker
+            .walk_next(hypergraph)
+            .map(|(_, neighbor_id, _)| neighbor_id)
+    }
+
+    /// The upper bound is the number of links of `source_id` not visited yet.
+    fn walk_size_hint(&self, hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> (usize, Option<usize>) {
+        self.walker.walk_size_hint(hypergraph)
     }
 }
 
@@ -95,4 +88,39 @@ mod tests {
         h.add_link([0], [2], "three").unwrap();
         assert_eq!(neighbor_walk.walk_next(&h).unwrap(), &vec![2]);
     }
+
+    #[test]
+    fn reset_and_set_direction() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        let mut neighbor_walk = WalkNeighbors::new(Direction::Outgoing, [2]);
+
+        assert_eq!(neighbor_walk.walk_next(&h).unwrap(), &vec![1]);
+        assert_eq!(neighbor_walk.walk_next(&h), None);
+        neighbor_walk.reset().set_direction(Direction::Incoming);
+        assert_eq!(neighbor_walk.walk_next(&h).unwrap(), &vec![0]);
+        assert_eq!(neighbor_walk.walk_next(&h), None);
+        h.add_node("five");
+        h.add_link([5], [2], ()).unwrap();
+        assert_eq!(neighbor_walk.walk_next(&h).unwrap(), &vec![5]);
+    }
+
+    #[test]
+    fn walk_next_self_edge() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_edge([0], [0], "one").unwrap();
+
+        for &direction in &[Direction::Outgoing, Direction::Incoming] {
+            let mut neighbor_walk = WalkNeighbors::new(direction, [0]);
+            assert_eq!(neighbor_walk.walk_next(&h).unwrap(), &vec![1]);
+            assert_eq!(neighbor_walk.walk_next(&h), None);
+
+            let mut neighbor_walk = WalkNeighbors::new(direction, [1]);
+            assert_eq!(neighbor_walk.walk_next(&h).unwrap(), &vec![0]);
+            assert_eq!(neighbor_walk.walk_next(&h), None);
+        }
+    }
 }
@@ -0,0 +1,108 @@
+use crate::{traits::Walker, Direction, Hypergraph};
+
+/// A “walker” object that can be used to step through a hypergraph without borrowing it.
+///
+/// Unlike [`WalkNeighbors`](struct.WalkNeighbors.html), which sees links added during the walk,
+/// it only visits the links that the source element had when the walker was created.
+/// Therefore, adding links to the source element while walking does not affect an ongoing walk
+/// (in particular, it can not make the walk infinite).
+#[derive(Debug, Clone)]
+pub struct WalkNeighborsSnapshot {
+    /// Direction to accept
+    direction: Direction,
+    /// Link counter over the links of the source element
+    next_link: usize,
+    /// Number of links of the source element when the walker was created
+    end: usize,
+    /// Id of the element whose neighbors are walked
+    source_id: Vec<usize>,
+}
+
+impl WalkNeighborsSnapshot {
+    /// Creates a walker over the current links of `source_id` in `hypergraph`.
+    ///
+    /// If `source_id` is not a linkable element of `hypergraph`, the walk is empty.
+    pub fn new<N, E, H, L, Ty>(
+        hypergraph: &Hypergraph<N, E, H, L, Ty>,
+        direction: Direction,
+        source_id: impl AsRef<[usize]>,
+    ) -> Self {
+        let source_id = source_id.as_ref();
+        let end = hypergraph
+            .links_of(source_id)
+            .map_or(0, |links| links.len());
+        WalkNeighborsSnapshot {
+            direction,
+            next_link: 0,
+            end,
+            source_id: source_id.to_vec(),
+        }
+    }
+}
+
+impl<'a, N, E, H, L, Ty> Walker<'a, N, E, H, L, Ty> for WalkNeighborsSnapshot {
+    type Item = &'a Vec<usize>;
+
+    /// Step to the next neighbor in the walk for `hypergraph`.
+    ///
+    /// Only the first links of the source element, as many as it had when `self` was created, are considered.
+    ///
+    /// # Remarks
+    ///
+    /// If `source_id` is not a valid id for `hypergraph`, it returns `None`.
+    fn walk_next(&mut self, hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> Option<Self::Item> {
+        let links = hypergraph.links_of(&self.source_id).ok()?;
+        while self.next_link < self.end {
+            let (link_id, direction) = links.get(self.next_link)?;
+            self.next_link += 1;
+            if direction == &self.direction {
+                let (source, target) = hypergraph.link_endpoints(link_id).unwrap(); // Never fails since link exists
+                return match direction {
+                    Direction::Outgoing => Some(target),
+                    Direction::Incoming => Some(source),
+                };
+            }
+        }
+        None
+    }
+
+    /// The upper bound is the number of links of the snapshot not visited yet.
+    fn walk_size_hint(&self, _: &'a Hypergraph<N, E, H, L, Ty>) -> (usize, Option<usize>) {
+        (0, Some(self.end.saturating_sub(self.next_link)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::walkers::WalkNeighbors;
+
+    #[test]
+    fn walk_next() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+
+        // Snapshot: links added during the walk are not visited
+        let mut snapshot = WalkNeighborsSnapshot::new(&h, Direction::Outgoing, [0]);
+        let mut visited = Vec::new();
+        while let Some(neighbor_id) = snapshot.walk_next(&h).cloned() {
+            h.add_link([0], &neighbor_id, ()).unwrap();
+            visited.push(neighbor_id);
+        }
+        assert_eq!(visited, vec![vec![2]]);
+
+        // Live: links added during the walk are visited
+        let mut live = WalkNeighbors::new(Direction::Outgoing, [0]);
+        let mut visited = Vec::new();
+        while let Some(neighbor_id) = live.walk_next(&h).cloned() {
+            if visited.len() < 3 {
+                h.add_link([0], &neighbor_id, ()).unwrap();
+            }
+            visited.push(neighbor_id);
+        }
+        // Two links from before the walk and three added during it
+        assert_eq!(visited.len(), 5);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{traits::Walker, Direction, Hypergraph};
+
+/// A “walker” object that can be used to step through a hypergraph without borrowing it.
+///
+/// Like [`WalkNeighbors`](struct.WalkNeighbors.html), but it also returns the link traversed
+/// and its direction.
+#[derive(Debug, Clone)]
+pub struct WalkNeighborsWithLink {
+    /// Direction to accept
+    direction: Direction,
+    /// Link counter over the links of the source element
+    next_link: usize,
+    /// Id of the element whose neighbors are walked
+    source_id: Vec<usize>,
+}
+
+impl WalkNeighborsWithLink {
+    pub fn new(direction: Direction, source_id: impl AsRef<[usize]>) -> Self {
+        let next_link = 0;
+        Self::new_from(direction, next_link, source_id)
+    }
+
+    pub fn new_from(
+        direction: Direction,
+        next_link: usize,
+        source_id: impl AsRef<[usize]>,
+    ) -> Self {
+        WalkNeighborsWithLink {
+            direction,
+            next_link,
+            source_id: source_id.as_ref().to_vec(),
+        }
+    }
+
+    /// Restarts the walk from the first link of the source element.
+    pub fn reset(&mut self) -> &mut Self {
+        self.next_link = 0;
+        self
+    }
+
+    /// Changes the direction of the neighbors to accept.
+    ///
+    /// The walk continues from the current link, call [`reset`](#method.reset) to start over.
+    pub fn set_direction(&mut self, direction: Direction) -> &mut Self {
+        self.direction = direction;
+        self
+    }
+}
+
+impl<'a, N, E, H, L, Ty> Walker<'a, N, E, H, L, Ty> for WalkNeighborsWithLink {
+    /// Link id, neighbor id and direction of the link from the perspective of the source element
+    type Item = (&'a Vec<usize>, &'a Vec<usize>, Direction);
+
+    /// Step to the next link, and its neighbor, in the walk for `hypergraph`.
+    ///
+    /// The walker advances in the neighbor count only if a link is found.
+    /// Therefore, if a link is added, the walker will see all new links (even if it returned `None` before).
+    ///
+    /// # Remarks
+    ///
+    /// If `source_id` is not a valid id for `hypergraph`, it returns `None`.
+    fn walk_next(&mut self, hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> Option<Self::Item> {
+        let links = match hypergraph.links_of(&self.source_id) {
+            Ok(links) => links,
+            Err(_) => return None,
+        };
+        loop {
+            let (link_id, direction) = links.get(self.next_link)?;
+            self.next_link += 1;
+            if direction == &self.direction {
+                let (source, target) = hypergraph.link_endpoints(link_id).unwrap(); // Never fails since link exists
+                let element_linkable_id = match direction {
+                    Direction::Outgoing => target,
+                    Direction::Incoming => source,
+                };
+                return Some((link_id, element_linkable_id, *direction));
+            }
+        }
+    }
+
+    /// The upper bound is the number of links of `source_id` not visited yet.
+    fn walk_size_hint(&self, hypergraph: &'a Hypergraph<N, E, H, L, Ty>) -> (usize, Option<usize>) {
+        match hypergraph.links_of(&self.source_id) {
+            Ok(links) => (0, Some(links.len().saturating_sub(self.next_link))),
+            Err(_) => (0, Some(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walk_next() {
+        let mut h = Hypergraph::<_, _>::new();
+        h.add_node("zero");
+        h.add_node("one");
+        h.add_edge([0], [1], "two").unwrap();
+        let mut walk = WalkNeighborsWithLink::new(Direction::Outgoing, [2]);
+
+        assert_eq!(
+            walk.walk_next(&h),
+            Some((&vec![4], &vec![1], Direction::Outgoing))
+        );
+        assert_eq!(walk.walk_next(&h), None);
+        walk.reset().set_direction(Direction::Incoming);
+        assert_eq!(
+            walk.walk_next(&h),
+            Some((&vec![3], &vec![0], Direction::Incoming))
+        );
+        assert_eq!(walk.walk_next(&h), None);
+    }
+}